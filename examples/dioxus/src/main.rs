@@ -1,6 +1,6 @@
 
 use accordion_rs::dioxus::{Accordion, Item, List};
-use accordion_rs::{Align, Size};
+use accordion_rs::{default_stylesheet, Align, Length, Size};
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
 
@@ -13,6 +13,7 @@ fn main() {
 #[component]
 fn app() -> Element {
     rsx! {
+        style { {default_stylesheet()} }
         Home {}
     }
 }
@@ -82,7 +83,7 @@ pub fn Home() -> Element {
                         class: "font-mono text-xs text-white p-4 bg-gray-800 mb-8 rounded-md w-full overflow-x-auto",
                         r#"Accordion  {{
     expand: expand_1,
-    size: Size::Custom("20rem".into()),
+    size: Size::Custom(Length::rem(20.0)),
     expanded: rsx!{{ h3 {{ class: "text-blue-500", "Styled Accordion Expanded" }} }},
     collapsed: rsx!{{ h3 {{ class: "text-red-500", "Styled Accordion Collapsed" }} }},
     class: "bg-gray-900 text-gray-400 border border-gray-700 p-4 rounded-md",
@@ -97,7 +98,7 @@ pub fn Home() -> Element {
                     }
                     Accordion {
                         expand: expand_1,
-                        size: Size::Custom("20rem"),
+                        size: Size::Custom(Length::rem(20.0)),
                         expanded: rsx!{ h3 { class: "text-blue-500", "Styled Accordion Expanded" } },
                         collapsed: rsx!{ h3 { class: "text-red-500", "Styled Accordion Collapsed" } },
                         class: "bg-gray-900 text-gray-400 border border-gray-700 p-4 rounded-md",
@@ -176,8 +177,8 @@ pub fn Home() -> Element {
     collapsed_class: "text-gray-400 bg-gray-900",
     duration: 300,
     List {{
-        Item {{ title: "Item with Icon", icon: "🔍", "Search content" }},
-        Item {{ title: "Another Icon Item", icon: "📦", "Package content" }},
+        Item {{ title: "Item with Icon", icon: rsx! {{ "🔍" }}, "Search content" }},
+        Item {{ title: "Another Icon Item", icon: rsx! {{ "📦" }}, "Package content" }},
     }},
 }}"#
                     }
@@ -191,8 +192,8 @@ pub fn Home() -> Element {
                         collapsed_class: "text-gray-400 bg-gray-900",
                         duration: 300,
                         List {
-                            Item { title: "Item with Icon", icon: "🔍", "Search content" }
-                            Item { title: "Another Icon Item", icon: "📦", "Package content" }
+                            Item { title: "Item with Icon", icon: rsx! { "🔍" }, "Search content" }
+                            Item { title: "Another Icon Item", icon: rsx! { "📦" }, "Package content" }
                         }
                     }
                 }
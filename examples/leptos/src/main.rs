@@ -1,11 +1,12 @@
 use accordion_rs::leptos::{Accordion, Item, List};
-use accordion_rs::{Align, Size};
+use accordion_rs::{default_stylesheet, Align, Length, Size};
 use leptos::logging::log;
 use leptos::prelude::*;
 
 #[component]
 pub fn App() -> impl IntoView {
     view! {
+        <style>{default_stylesheet()}</style>
         <Home />
     }
 }
@@ -22,9 +23,9 @@ pub fn Home() -> impl IntoView {
     let expand_7 = signal(false);
     let expand_8 = signal(false);
 
-    let on_will_open = Callback::from(move || log!("Accordion will open!"));
+    let on_will_open = Callback::new(move |_| log!("Accordion will open!"));
     let on_did_open = Callback::from(move || log!("Accordion did open!"));
-    let on_will_close = Callback::from(move || log!("Accordion will close!"));
+    let on_will_close = Callback::new(move |_| log!("Accordion will close!"));
     let on_did_close = Callback::from(move || log!("Accordion did close!"));
 
     view! {
@@ -75,7 +76,7 @@ pub fn Home() -> impl IntoView {
                     >
                         { r#"<Accordion
     expand={expanded}
-    size={Size::Custom("20rem")}
+    size={Size::Custom(Length::rem(20.0))}
     expanded={Box::new(|| view! { <h3 class="text-blue-500">"Styled Accordion Expanded"</h3> })}
     collapsed={Box::new(|| view! { <h3 class="text-red-500">"Styled Accordion Collapsed"</h3> })}
     class="bg-gray-900 text-gray-400 border border-gray-700 p-4 rounded-md"
@@ -95,7 +96,7 @@ pub fn Home() -> impl IntoView {
                     </pre>
                     <Accordion
                         expand={expand_1}
-                        size=Size::Custom("20rem")
+                        size=Size::Custom(Length::rem(20.0))
                         expanded=Box::new(|| view! { <h3 class="text-blue-500">"Styled Accordion Expanded"</h3> }.into_any())
                         collapsed=Box::new(|| view! { <h3 class="text-red-500">"Styled Accordion Collapsed"</h3> }.into_any())
                         class="bg-gray-900 text-gray-400 border border-gray-700 p-4 rounded-md"
@@ -185,8 +186,8 @@ pub fn Home() -> impl IntoView {
     duration=300
 >
     <List>
-        <Item title="Item with Icon" icon="🔍">"Search content"</Item>
-        <Item title="Another Icon Item" icon="📦">"Package content"</Item>
+        <Item title="Item with Icon" icon=|| "🔍">"Search content"</Item>
+        <Item title="Another Icon Item" icon=|| "📦">"Package content"</Item>
     </List>
 </Accordion>"# }
                     </pre>
@@ -201,8 +202,8 @@ pub fn Home() -> impl IntoView {
                         duration=300
                     >
                         <List>
-                            <Item title="Item with Icon" icon="🔍">"Search content"</Item>
-                            <Item title="Another Icon Item" icon="📦">"Package content"</Item>
+                            <Item title="Item with Icon" icon=|| "🔍">"Search content"</Item>
+                            <Item title="Another Icon Item" icon=|| "📦">"Package content"</Item>
                         </List>
                     </Accordion>
                 </div>
@@ -1,3 +1,4 @@
+use accordion_rs::default_stylesheet;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -6,8 +7,11 @@ use crate::router::{switch, Route};
 #[function_component(App)]
 pub fn app() -> Html {
     html! {
+      <>
+      <style>{ default_stylesheet() }</style>
       <BrowserRouter>
            <Switch<Route> render={switch} />
       </BrowserRouter>
+      </>
     }
 }
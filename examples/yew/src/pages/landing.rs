@@ -1,5 +1,5 @@
 use accordion_rs::yew::{Accordion, Item, List};
-use accordion_rs::{Align, Size};
+use accordion_rs::{Align, Length, Size};
 use yew::prelude::*;
 
 #[function_component(LandingPage)]
@@ -66,7 +66,7 @@ pub fn landing_page() -> Html {
                     >
                         { r#"<Accordion
     expand={expand_state}
-    size={Size::Custom("20rem")}
+    size={Size::Custom(Length::rem(20.0))}
     expanded={html! { <h3 class="text-blue-500">{"Styled Accordion Expanded"}</h3> }}
     collapsed={html! { <h3 class="text-red-500">{"Styled Accordion Collapsed"}</h3> }}
     class="bg-gray-900 text-gray-400 border border-gray-700 p-4 rounded-md"
@@ -86,7 +86,7 @@ pub fn landing_page() -> Html {
                     </pre>
                     <Accordion
                         expand={expand_1}
-                        size={Size::Custom("20rem")}
+                        size={Size::Custom(Length::rem(20.0))}
                         expanded={html! { <h3 class="text-blue-500">{"Styled Accordion Expanded"}</h3> }}
                         collapsed={html! { <h3 class="text-red-500">{"Styled Accordion Collapsed"}</h3> }}
                         class="bg-gray-900 text-gray-400 border border-gray-700 p-4 rounded-md"
@@ -180,10 +180,10 @@ pub fn landing_page() -> Html {
     duration={300}
 >
     <List>
-        <Item title="Item with Icon" icon="🔍">
+        <Item title="Item with Icon" icon={html! { "🔍" }}>
             {"Search content"}
         </Item>
-        <Item title="Another Icon Item" icon="📦">
+        <Item title="Another Icon Item" icon={html! { "📦" }}>
             {"Package content"}
         </Item>
     </List>
@@ -200,8 +200,8 @@ pub fn landing_page() -> Html {
                         duration=300
                     >
                         <List>
-                            <Item title="Item with Icon" icon="🔍">{ "Search content" }</Item>
-                            <Item title="Another Icon Item" icon="📦">{ "Package content" }</Item>
+                            <Item title="Item with Icon" icon={html! { "🔍" }}>{ "Search content" }</Item>
+                            <Item title="Another Icon Item" icon={html! { "📦" }}>{ "Package content" }</Item>
                         </List>
                     </Accordion>
                 </div>
@@ -1,3 +1,13 @@
+use std::borrow::Cow;
+use std::cell::Cell;
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
 /// Accordion Size
 #[derive(Clone, PartialEq, Default)]
 pub enum Size {
@@ -8,21 +18,543 @@ pub enum Size {
     XLarge,
     #[default]
     XXLarge,
-    Custom(&'static str),
+    /// Any width, e.g. `Size::Custom(Length::rem(40.0))` or one parsed at runtime.
+    Custom(Length),
+    /// The full width of the containing block.
+    Full,
+    /// The full width of the containing block, up to the given width.
+    MaxWidth(Length),
+    /// The full width of the containing block, kept between two widths.
+    Fluid {
+        min: Length,
+        max: Length,
+    },
+    /// A width with optional bounds and height, built with `Dimensions`.
+    Dimensions(Dimensions),
+    /// A size switching at viewport or container breakpoints, built with `ResponsiveSize`.
+    Responsive(Box<ResponsiveSize>),
 }
 
 impl Size {
+    /// The width of the size, as set on the `--accordion-width` custom property.
+    ///
+    /// A responsive size reports the width of its base size.
+    pub fn width(&self) -> Length {
+        match self {
+            Size::Small => Length::Rem(7.0),
+            Size::Medium => Length::Rem(10.0),
+            Size::Large => Length::Rem(20.0),
+            Size::XSmall => Length::Rem(5.0),
+            Size::XLarge => Length::Rem(25.0),
+            Size::XXLarge => Length::Rem(30.0),
+            Size::Custom(width) => width.clone(),
+            Size::Full | Size::MaxWidth(_) | Size::Fluid { .. } => Length::Percent(100.0),
+            Size::Dimensions(dimensions) => dimensions.width.clone(),
+            Size::Responsive(responsive) => responsive.base.width(),
+        }
+    }
+
+    /// The min-width of the size, as set on the `--accordion-min-width` custom property.
+    pub fn min_width(&self) -> Option<&Length> {
+        match self {
+            Size::Fluid { min, .. } => Some(min),
+            Size::Dimensions(dimensions) => dimensions.min_width.as_ref(),
+            Size::Responsive(responsive) => responsive.base.min_width(),
+            _ => None,
+        }
+    }
+
+    /// The max-width of the size, as set on the `--accordion-max-width` custom property.
+    pub fn max_width(&self) -> Option<&Length> {
+        match self {
+            Size::MaxWidth(max_width) | Size::Fluid { max: max_width, .. } => Some(max_width),
+            Size::Dimensions(dimensions) => dimensions.max_width.as_ref(),
+            Size::Responsive(responsive) => responsive.base.max_width(),
+            _ => None,
+        }
+    }
+
+    /// The height of the size, as set on the `--accordion-height` custom property.
+    pub fn height(&self) -> Option<&Length> {
+        match self {
+            Size::Dimensions(dimensions) => dimensions.height.as_ref(),
+            Size::Responsive(responsive) => responsive.base.height(),
+            _ => None,
+        }
+    }
+
     pub fn to_style(&self) -> String {
+        let mut style = format!("width: {};", self.width());
+        for (property, value, _) in self.bounds() {
+            if let Some(value) = value {
+                style.push_str(&format!(" {property}: {value};"));
+            }
+        }
+        style
+    }
+
+    /// CSS rules sizing the elements matched by `selector`, through the accordion's custom properties.
+    ///
+    /// A responsive size adds a `@media` or `@container` rule for each of its breakpoints.
+    pub fn to_css(&self, selector: &str) -> String {
+        let mut css = format!("{selector} {{ {}}}\n", self.declarations());
+        if let Size::Responsive(responsive) = self {
+            for (breakpoint, size) in &responsive.breakpoints {
+                css.push_str(&format!(
+                    "@{} (min-width: {}) {{ {selector} {{ {}}} }}\n",
+                    responsive.query.at_rule(),
+                    breakpoint.min_width(),
+                    size.declarations()
+                ));
+            }
+        }
+        css
+    }
+
+    /// Whether the size changes at breakpoints, which inline styles can't express.
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    pub(crate) fn is_responsive(&self) -> bool {
+        matches!(self, Size::Responsive(_))
+    }
+
+    /// The optional properties of the size, with the initial values that reset them.
+    fn bounds(&self) -> [(&'static str, Option<&Length>, &'static str); 3] {
+        [
+            ("min-width", self.min_width(), "auto"),
+            ("max-width", self.max_width(), "none"),
+            ("height", self.height(), "auto"),
+        ]
+    }
+
+    /// `--accordion-width`, `--accordion-min-width`, `--accordion-max-width` and
+    /// `--accordion-height` declarations for the size.
+    ///
+    /// A size without a bound or height sets it back to its initial value, so that it overrides
+    /// one inherited from an enclosing accordion or applied at a narrower breakpoint.
+    pub(crate) fn declarations(&self) -> String {
+        let mut style = format!("--accordion-width: {}; ", self.width());
+        for (property, value, initial) in self.bounds() {
+            let value = value.map_or_else(|| initial.to_string(), ToString::to_string);
+            style.push_str(&format!("--accordion-{property}: {value}; "));
+        }
+        style
+    }
+}
+
+/// A width with optional min-width, max-width and height, for `Size::Dimensions`.
+///
+/// ```rust
+/// use accordion_rs::{Dimensions, Length, Size};
+///
+/// let size = Size::Dimensions(
+///     Dimensions::new(Length::percent(100.0))
+///         .max_width(Length::rem(40.0))
+///         .height(Length::px(480.0)),
+/// );
+/// assert_eq!(size.to_style(), "width: 100%; max-width: 40rem; height: 480px;");
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Dimensions {
+    pub width: Length,
+    pub min_width: Option<Length>,
+    pub max_width: Option<Length>,
+    pub height: Option<Length>,
+}
+
+impl Dimensions {
+    pub fn new(width: Length) -> Self {
+        Self {
+            width,
+            min_width: None,
+            max_width: None,
+            height: None,
+        }
+    }
+
+    pub fn min_width(mut self, min_width: Length) -> Self {
+        self.min_width = Some(min_width);
+        self
+    }
+
+    pub fn max_width(mut self, max_width: Length) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = Some(height);
+        self
+    }
+}
+
+/// A CSS length in one of the units the accordion supports, or a `calc()` expression.
+///
+/// Lengths of the same unit add, subtract and scale directly, while mixing units produces a
+/// `calc()`. Parsing a string checks the number and unit, so typos surface as errors instead of
+/// silently invalid styles. CSS has no infinite or NaN lengths, so arithmetic that doesn't give a
+/// finite number, such as dividing by zero, gives a zero length.
+///
+/// ```rust
+/// use accordion_rs::{Length, ParseLengthError};
+///
+/// assert_eq!(Length::rem(1.5) * 2.0, Length::rem(3.0));
+/// assert_eq!((Length::percent(100.0) - Length::rem(2.0)).to_string(), "calc(100% - 2rem)");
+/// assert_eq!("12.5vw".parse(), Ok(Length::vw(12.5)));
+/// assert_eq!(Length::px(10.0) / 0.0, Length::px(0.0));
+/// assert_eq!(
+///     "20 rem".parse::<Length>(),
+///     Err(ParseLengthError::UnknownUnit(" rem".to_string()))
+/// );
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub enum Length {
+    Px(f32),
+    Rem(f32),
+    Em(f32),
+    Percent(f32),
+    Vw(f32),
+    /// A `calc()` expression, holding what goes between its parentheses.
+    Calc(Cow<'static, str>),
+}
+
+impl Length {
+    pub fn px(value: f32) -> Self {
+        Length::Px(value)
+    }
+
+    pub fn rem(value: f32) -> Self {
+        Length::Rem(value)
+    }
+
+    pub fn em(value: f32) -> Self {
+        Length::Em(value)
+    }
+
+    pub fn percent(value: f32) -> Self {
+        Length::Percent(value)
+    }
+
+    pub fn vw(value: f32) -> Self {
+        Length::Vw(value)
+    }
+
+    /// A `calc()` of the given expression, e.g. `Length::calc("100% - 2rem")`.
+    ///
+    /// The expression isn't checked; parse `"calc(...)"` to check its parentheses.
+    pub fn calc(expression: impl Into<Cow<'static, str>>) -> Self {
+        Length::Calc(expression.into())
+    }
+
+    /// The length as an operand of a `calc()` expression.
+    fn operand(&self) -> String {
         match self {
-            Size::Small => "width: 7rem;".to_string(),
-            Size::Medium => "width: 10rem;".to_string(),
-            Size::Large => "width: 20rem;".to_string(),
-            Size::XSmall => "width: 5rem;".to_string(),
-            Size::XLarge => "width: 25rem;".to_string(),
-            Size::XXLarge => "width: 30rem;".to_string(),
-            Size::Custom(custom_size) => format!("width: {}; ", custom_size),
+            Length::Calc(expression) => format!("({expression})"),
+            length => length.to_string(),
+        }
+    }
+
+    /// Combines two lengths with `op`, folding them when they share a unit.
+    fn combine(self, rhs: Self, op: char, fold: fn(f32, f32) -> f32) -> Self {
+        let fold = |a, b| finite(fold(a, b));
+        match (self, rhs) {
+            (Length::Px(a), Length::Px(b)) => Length::Px(fold(a, b)),
+            (Length::Rem(a), Length::Rem(b)) => Length::Rem(fold(a, b)),
+            (Length::Em(a), Length::Em(b)) => Length::Em(fold(a, b)),
+            (Length::Percent(a), Length::Percent(b)) => Length::Percent(fold(a, b)),
+            (Length::Vw(a), Length::Vw(b)) => Length::Vw(fold(a, b)),
+            (lhs, rhs) => Length::calc(format!("{} {op} {}", lhs.operand(), rhs.operand())),
         }
     }
+
+    /// Scales the length with `op`, folding the factor into a plain length.
+    fn scale(self, factor: f32, op: char, fold: fn(f32, f32) -> f32) -> Self {
+        let scaled = |value| finite(fold(value, factor));
+        match self {
+            Length::Px(value) => Length::Px(scaled(value)),
+            Length::Rem(value) => Length::Rem(scaled(value)),
+            Length::Em(value) => Length::Em(scaled(value)),
+            Length::Percent(value) => Length::Percent(scaled(value)),
+            Length::Vw(value) => Length::Vw(scaled(value)),
+            _ if !fold(1.0, factor).is_finite() => Length::Px(0.0),
+            length => Length::calc(format!("{} {op} {factor}", length.operand())),
+        }
+    }
+}
+
+/// `value`, or zero if it is infinite or NaN.
+fn finite(value: f32) -> f32 {
+    if value.is_finite() {
+        value
+    } else {
+        0.0
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Length::Px(value) => write!(f, "{value}px"),
+            Length::Rem(value) => write!(f, "{value}rem"),
+            Length::Em(value) => write!(f, "{value}em"),
+            Length::Percent(value) => write!(f, "{value}%"),
+            Length::Vw(value) => write!(f, "{value}vw"),
+            Length::Calc(expression) => write!(f, "calc({expression})"),
+        }
+    }
+}
+
+impl Add for Length {
+    type Output = Length;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, '+', |a, b| a + b)
+    }
+}
+
+impl Sub for Length {
+    type Output = Length;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, '-', |a, b| a - b)
+    }
+}
+
+impl Mul<f32> for Length {
+    type Output = Length;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.scale(rhs, '*', |a, b| a * b)
+    }
+}
+
+impl Div<f32> for Length {
+    type Output = Length;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        self.scale(rhs, '/', |a, b| a / b)
+    }
+}
+
+impl FromStr for Length {
+    type Err = ParseLengthError;
+
+    /// Parses a number followed by `px`, `rem`, `em`, `%` or `vw`, a unitless `0`, or a
+    /// `calc(...)` expression with balanced parentheses.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseLengthError::Empty);
+        }
+        if let Some(expression) = s.strip_prefix("calc(").and_then(|s| s.strip_suffix(')')) {
+            let mut depth = 0usize;
+            for c in expression.chars() {
+                match c {
+                    '(' => depth += 1,
+                    ')' if depth == 0 => return Err(ParseLengthError::InvalidCalc(s.to_string())),
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+            }
+            if depth != 0 || expression.trim().is_empty() {
+                return Err(ParseLengthError::InvalidCalc(s.to_string()));
+            }
+            return Ok(Length::calc(expression.trim().to_string()));
+        }
+        let split = s
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')))
+            .unwrap_or(s.len());
+        let (number, unit) = s.split_at(split);
+        let value = number
+            .parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .ok_or_else(|| ParseLengthError::InvalidNumber(s.to_string()))?;
+        match unit {
+            "px" => Ok(Length::Px(value)),
+            "rem" => Ok(Length::Rem(value)),
+            "em" => Ok(Length::Em(value)),
+            "%" => Ok(Length::Percent(value)),
+            "vw" => Ok(Length::Vw(value)),
+            "" if value == 0.0 => Ok(Length::Px(0.0)),
+            unit => Err(ParseLengthError::UnknownUnit(unit.to_string())),
+        }
+    }
+}
+
+/// Error returned when parsing a `Length` from a string fails.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParseLengthError {
+    /// The string is empty.
+    Empty,
+    /// The number is missing or malformed, e.g. `"rem"` or `"1.2.3px"`.
+    InvalidNumber(String),
+    /// The unit isn't `px`, `rem`, `em`, `%` or `vw`, e.g. the `" rem"` of `"20 rem"`.
+    UnknownUnit(String),
+    /// The `calc()` expression is empty or has unbalanced parentheses.
+    InvalidCalc(String),
+}
+
+impl fmt::Display for ParseLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseLengthError::Empty => write!(f, "empty CSS length"),
+            ParseLengthError::InvalidNumber(length) => {
+                write!(f, "invalid number in CSS length `{length}`")
+            }
+            ParseLengthError::UnknownUnit(unit) => {
+                write!(
+                    f,
+                    "unknown CSS length unit `{unit}`, expected px, rem, em, % or vw"
+                )
+            }
+            ParseLengthError::InvalidCalc(length) => {
+                write!(f, "invalid calc() expression `{length}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseLengthError {}
+
+/// A `Size` depending on the width of the viewport or of the accordion's container.
+///
+/// The base size applies below the first breakpoint, and each breakpoint's size applies from its
+/// width upwards, so add breakpoints from the narrowest to the widest.
+///
+/// ```rust
+/// use accordion_rs::{Breakpoint, ResponsiveSize, Size};
+///
+/// let size: Size = ResponsiveSize::new(Size::Full)
+///     .at(Breakpoint::Md, Size::Large)
+///     .at(Breakpoint::Xl, Size::XXLarge)
+///     .into();
+/// ```
+#[derive(Clone, PartialEq, Default)]
+pub struct ResponsiveSize {
+    base: Size,
+    query: SizeQuery,
+    breakpoints: Vec<(Breakpoint, Size)>,
+}
+
+impl ResponsiveSize {
+    /// A size following the viewport width through `@media` queries.
+    pub fn new(base: Size) -> Self {
+        Self {
+            base,
+            ..Self::default()
+        }
+    }
+
+    /// A size following the width of the nearest ancestor with `container-type: inline-size`,
+    /// through `@container` queries.
+    pub fn container(base: Size) -> Self {
+        Self {
+            base,
+            query: SizeQuery::Container,
+            ..Self::default()
+        }
+    }
+
+    /// Uses `size` from the `breakpoint` width upwards.
+    pub fn at(mut self, breakpoint: Breakpoint, size: Size) -> Self {
+        self.breakpoints.push((breakpoint, size));
+        self
+    }
+}
+
+impl From<ResponsiveSize> for Size {
+    fn from(responsive: ResponsiveSize) -> Self {
+        Size::Responsive(Box::new(responsive))
+    }
+}
+
+/// What the breakpoints of a `ResponsiveSize` are measured against.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+enum SizeQuery {
+    #[default]
+    Viewport,
+    Container,
+}
+
+impl SizeQuery {
+    fn at_rule(self) -> &'static str {
+        match self {
+            SizeQuery::Viewport => "media",
+            SizeQuery::Container => "container",
+        }
+    }
+}
+
+/// Minimum width from which a `ResponsiveSize` breakpoint applies.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Breakpoint {
+    /// `640px`
+    Sm,
+    /// `768px`
+    Md,
+    /// `1024px`
+    Lg,
+    /// `1280px`
+    Xl,
+    /// `1536px`
+    Xxl,
+    /// Any width, e.g. `Breakpoint::Custom(Length::em(50.0))`.
+    Custom(Length),
+}
+
+impl Breakpoint {
+    /// The width from which the breakpoint applies.
+    pub fn min_width(&self) -> Length {
+        match self {
+            Breakpoint::Sm => Length::Px(640.0),
+            Breakpoint::Md => Length::Px(768.0),
+            Breakpoint::Lg => Length::Px(1024.0),
+            Breakpoint::Xl => Length::Px(1280.0),
+            Breakpoint::Xxl => Length::Px(1536.0),
+            Breakpoint::Custom(min_width) => min_width.clone(),
+        }
+    }
+}
+
+/// Attribute selector matching the container of the accordion with the given base id.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn size_selector(id: &str) -> String {
+    format!(
+        "[data-accordion-id=\"{}\"]",
+        id.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+/// Side of the header or item content on which an icon is placed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Placement {
+    /// Before the content, on the inline-start side.
+    #[default]
+    Leading,
+    /// After the content, on the inline-end side.
+    Trailing,
+}
+
+impl Placement {
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Placement::Leading => "leading",
+            Placement::Trailing => "trailing",
+        }
+    }
+}
+
+/// Accessible label of an accordion's expand/collapse indicator.
+///
+/// Falls back to "Collapse" or "Expand", describing what activating the header does.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn indicator_label(own: &str, expanded: bool) -> &str {
+    match own {
+        "" if expanded => "Collapse",
+        "" => "Expand",
+        own => own,
+    }
 }
 
 /// Accordion Item Alignment
@@ -36,7 +568,8 @@ pub enum Align {
     Start,
     End,
     MatchParent,
-    Custom(&'static str),
+    /// Any CSS `text-align` value, e.g. `Align::Custom("-webkit-center".into())`.
+    Custom(Cow<'static, str>),
 }
 
 impl Align {
@@ -55,3 +588,1377 @@ impl Align {
         }
     }
 }
+
+/// Classes and inline styles shared by accordions and their lists and items.
+///
+/// Passing a theme to an `Accordion` styles its container, header, panel and the `List`s and `Item`s
+/// inside it, along with nested accordions that don't set their own. An instance's `*_class` and
+/// `*_style` props override the matching theme value when they aren't empty.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Theme {
+    /// Class of the accordion container.
+    pub class: Cow<'static, str>,
+    /// Inline style of the accordion container.
+    pub style: Cow<'static, str>,
+    /// Class of the header button, in both states.
+    pub header_class: Cow<'static, str>,
+    /// Inline style of the header button, in both states.
+    pub header_style: Cow<'static, str>,
+    /// Class of the header button while expanded.
+    pub expanded_class: Cow<'static, str>,
+    /// Inline style of the header button while expanded.
+    pub expanded_style: Cow<'static, str>,
+    /// Class of the header button while collapsed.
+    pub collapsed_class: Cow<'static, str>,
+    /// Inline style of the header button while collapsed.
+    pub collapsed_style: Cow<'static, str>,
+    /// Class of the panel.
+    pub content_class: Cow<'static, str>,
+    /// Inline style of the panel.
+    pub content_style: Cow<'static, str>,
+    /// Class of `List` containers.
+    pub list_class: Cow<'static, str>,
+    /// Inline style of `List` containers.
+    pub list_style: Cow<'static, str>,
+    /// Class of `Item`s.
+    pub item_class: Cow<'static, str>,
+    /// Inline style of `Item`s.
+    pub item_style: Cow<'static, str>,
+}
+
+impl Theme {
+    /// Dark text on white panels with light gray borders.
+    pub fn light() -> Self {
+        Self {
+            style: Cow::Borrowed("background-color: #ffffff; color: #1f2937; border: 1px solid #e5e7eb; border-radius: 8px;"),
+            header_style: Cow::Borrowed("padding: 12px 16px; text-align: left; font-weight: 600;"),
+            expanded_style: Cow::Borrowed("background-color: #f3f4f6;"),
+            collapsed_style: Cow::Borrowed("background-color: #ffffff;"),
+            content_style: Cow::Borrowed("padding: 0 16px; color: #374151;"),
+            list_style: Cow::Borrowed("list-style: none; margin: 0; padding: 8px 0;"),
+            item_style: Cow::Borrowed("padding: 6px 0; border-bottom: 1px solid #f3f4f6;"),
+            ..Self::default()
+        }
+    }
+
+    /// Light text on near-black panels with dark gray borders.
+    pub fn dark() -> Self {
+        Self {
+            style: Cow::Borrowed("background-color: #111827; color: #f9fafb; border: 1px solid #374151; border-radius: 8px;"),
+            header_style: Cow::Borrowed("padding: 12px 16px; text-align: left; font-weight: 600;"),
+            expanded_style: Cow::Borrowed("background-color: #1f2937;"),
+            collapsed_style: Cow::Borrowed("background-color: #111827;"),
+            content_style: Cow::Borrowed("padding: 0 16px; color: #d1d5db;"),
+            list_style: Cow::Borrowed("list-style: none; margin: 0; padding: 8px 0;"),
+            item_style: Cow::Borrowed("padding: 6px 0; border-bottom: 1px solid #1f2937;"),
+            ..Self::default()
+        }
+    }
+
+    /// `own` unless it is empty, in which case the theme's `themed` value.
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    pub(crate) fn pick<'a>(own: &'a str, themed: &'a str) -> &'a str {
+        if own.is_empty() {
+            themed
+        } else {
+            own
+        }
+    }
+}
+
+/// App-wide defaults supplied by an `AccordionConfigProvider` to every accordion inside it.
+///
+/// Unset fields fall back to the surrounding provider's value, then to the accordion's own
+/// defaults; an accordion's explicit props always win over the provided ones.
+#[derive(Clone, PartialEq, Default)]
+pub struct AccordionConfig {
+    /// Theme of accordions that neither set one nor inherit one from a parent accordion.
+    pub theme: Option<Theme>,
+    /// Animation duration in milliseconds.
+    pub duration: Option<u64>,
+    /// Size of the accordion container.
+    pub size: Option<Size>,
+    /// Whether ARIA attributes are rendered.
+    pub aria_enabled: Option<bool>,
+    /// Heading level of top-level accordions; nested ones still step one level below their parent.
+    pub heading_level: Option<u8>,
+}
+
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+impl AccordionConfig {
+    /// `self`, with the unset fields taken from the `outer` provider's config.
+    pub(crate) fn or(self, outer: Option<&Self>) -> Self {
+        let Some(outer) = outer else { return self };
+        Self {
+            theme: self.theme.or_else(|| outer.theme.clone()),
+            duration: self.duration.or(outer.duration),
+            size: self.size.or_else(|| outer.size.clone()),
+            aria_enabled: self.aria_enabled.or(outer.aria_enabled),
+            heading_level: self.heading_level.or(outer.heading_level),
+        }
+    }
+
+    /// Effective animation duration: `own`, the provided one, or `600` milliseconds.
+    pub(crate) fn duration(&self, own: Option<u64>) -> u64 {
+        own.or(self.duration).unwrap_or(600)
+    }
+
+    /// Inline custom properties for the duration and size set on the accordion or its provider.
+    ///
+    /// Values left unset aren't emitted, so the inline fallbacks and CSS overrides apply. A size
+    /// resets the bounds it leaves unset, and a `nested` accordion without a size resets all of
+    /// them, so neither inherits the size of an enclosing accordion. A responsive size sets them
+    /// in its `<style>` rules instead, which inline declarations would override.
+    pub(crate) fn properties(
+        &self,
+        duration: Option<u64>,
+        size: Option<&Size>,
+        nested: bool,
+    ) -> String {
+        let mut style = String::new();
+        if let Some(duration) = duration.or(self.duration) {
+            style.push_str(&format!("--accordion-duration: {duration}ms; "));
+        }
+        match self.size(size) {
+            Some(size) if size.is_responsive() => {}
+            Some(size) => style.push_str(&size.declarations()),
+            None if nested => style.push_str(&Size::default().declarations()),
+            None => {}
+        }
+        style
+    }
+
+    /// The size set on the accordion or its provider, if any.
+    pub(crate) fn size<'a>(&'a self, own: Option<&'a Size>) -> Option<&'a Size> {
+        own.or(self.size.as_ref())
+    }
+
+    /// The `<style>` rules of a responsive size set on the accordion or its provider, scoped to
+    /// the accordion with the base id `id`.
+    pub(crate) fn size_rules(&self, own: Option<&Size>, id: &str) -> Option<String> {
+        self.size(own)
+            .filter(|size| size.is_responsive())
+            .map(|size| size.to_css(&size_selector(id)))
+    }
+
+    /// Whether ARIA attributes are rendered: `own`, the provided value, or `true`.
+    pub(crate) fn aria_enabled(&self, own: Option<bool>) -> bool {
+        own.or(self.aria_enabled).unwrap_or(true)
+    }
+
+    /// Effective heading level: `own`, one below the `parent`'s, the provided one, or `3`.
+    pub(crate) fn heading_level(&self, own: Option<u8>, parent: Option<u8>) -> u8 {
+        match (own, parent) {
+            (Some(level), _) => level.clamp(1, 6),
+            (None, Some(_)) => nested_heading_level(parent),
+            (None, None) => self.heading_level.map_or(3, |level| level.clamp(1, 6)),
+        }
+    }
+}
+
+/// Open/closed bookkeeping shared by the `AccordionGroup` components.
+///
+/// Accordions register with their group in render order and are identified by the
+/// returned key. The state only records which keys are currently open.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+#[derive(Clone, PartialEq, Debug, Default)]
+pub(crate) struct GroupState {
+    open: Vec<usize>,
+}
+
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+impl GroupState {
+    /// Whether the accordion registered under `key` is open.
+    pub(crate) fn is_open(&self, key: usize) -> bool {
+        self.open.contains(&key)
+    }
+
+    /// Marks `key` as open. In `single` mode every other key is closed.
+    pub(crate) fn open(&mut self, key: usize, single: bool) {
+        if single {
+            self.open.clear();
+        }
+        if !self.open.contains(&key) {
+            self.open.push(key);
+        }
+    }
+
+    /// Marks `key` as closed.
+    pub(crate) fn close(&mut self, key: usize) {
+        self.open.retain(|open| *open != key);
+    }
+
+    /// Opens or closes `key`.
+    pub(crate) fn set_open(&mut self, key: usize, open: bool, single: bool) {
+        if open {
+            self.open(key, single);
+        } else {
+            self.close(key);
+        }
+    }
+
+    /// Opens or closes the live `keys`. In `single` mode only the first of them is opened.
+    pub(crate) fn set_all(&mut self, keys: &[usize], open: bool, single: bool) {
+        self.open = match (open, single) {
+            (false, _) => Vec::new(),
+            (true, true) => keys.iter().copied().take(1).collect(),
+            (true, false) => keys.to_vec(),
+        };
+    }
+
+    /// Whether all, none or some of the live `keys` are open.
+    pub(crate) fn status(&self, keys: &[usize]) -> GroupStatus {
+        let open = keys.iter().filter(|key| self.is_open(**key)).count();
+        if open == 0 {
+            GroupStatus::NoneOpen
+        } else if open == keys.len() {
+            GroupStatus::AllOpen
+        } else {
+            GroupStatus::SomeOpen
+        }
+    }
+
+    /// Encodes which of the live `keys` are open for web storage, as a comma-separated list of
+    /// their positions, so the saved value follows the order of the accordions on the page.
+    pub(crate) fn encode(&self, keys: &[usize]) -> String {
+        keys.iter()
+            .enumerate()
+            .filter(|(_, key)| self.is_open(**key))
+            .map(|(position, _)| position.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Decodes a value written by `encode` against the live `keys`, skipping anything that isn't
+    /// the position of one of them.
+    pub(crate) fn decode(value: &str, keys: &[usize]) -> Self {
+        let mut open = Vec::new();
+        for key in value
+            .split(',')
+            .filter_map(|position| position.trim().parse::<usize>().ok())
+            .filter_map(|position| keys.get(position).copied())
+        {
+            if !open.contains(&key) {
+                open.push(key);
+            }
+        }
+        Self { open }
+    }
+}
+
+/// Ids of the accordions registered with an `AccordionGroup`, indexed by their key.
+///
+/// Keys stay stable for an accordion's lifetime: unregistering leaves an empty slot behind.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+#[derive(Clone, PartialEq, Debug, Default)]
+pub(crate) struct GroupMembers {
+    ids: Vec<Option<String>>,
+}
+
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+impl GroupMembers {
+    /// Hands out the key of the next accordion joining the group.
+    pub(crate) fn register(&mut self) -> usize {
+        self.ids.push(Some(String::new()));
+        self.ids.len() - 1
+    }
+
+    /// Removes the accordion registered under `key` from the group.
+    pub(crate) fn unregister(&mut self, key: usize) {
+        if let Some(slot) = self.ids.get_mut(key) {
+            *slot = None;
+        }
+    }
+
+    /// Records the base id of the accordion registered under `key`.
+    pub(crate) fn set_id(&mut self, key: usize, id: &str) {
+        if let Some(slot) = self
+            .ids
+            .get_mut(key)
+            .and_then(Option::as_mut)
+            .filter(|slot| *slot != id)
+        {
+            *slot = id.to_string();
+        }
+    }
+
+    /// Key of the accordion whose base or panel id is `id`.
+    pub(crate) fn key(&self, id: &str) -> Option<usize> {
+        self.ids.iter().position(|member| {
+            member.as_deref().is_some_and(|member| {
+                member == id || id.strip_suffix("-panel").is_some_and(|base| member == base)
+            })
+        })
+    }
+
+    /// Keys of the accordions currently registered with the group, in registration order.
+    pub(crate) fn keys(&self) -> Vec<usize> {
+        self.ids
+            .iter()
+            .enumerate()
+            .filter(|(_, member)| member.is_some())
+            .map(|(key, _)| key)
+            .collect()
+    }
+}
+
+/// How many accordions of an `AccordionGroup` are open.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GroupStatus {
+    /// Every accordion is open.
+    AllOpen,
+    /// No accordion is open, which includes an empty group.
+    NoneOpen,
+    /// Some accordions are open and others are closed.
+    SomeOpen,
+}
+
+/// Change requested through an accordion's controller handle.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub(crate) enum ControlAction {
+    #[default]
+    Toggle,
+    Open,
+    Close,
+}
+
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+impl ControlAction {
+    /// Whether the action toggles an accordion that is currently `expanded`.
+    pub(crate) fn applies(self, expanded: bool) -> bool {
+        match self {
+            ControlAction::Toggle => true,
+            ControlAction::Open => !expanded,
+            ControlAction::Close => expanded,
+        }
+    }
+}
+
+/// Latest change requested through a controller handle.
+///
+/// The sequence number tells repeated requests apart, so the accordion runs each of them once.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub(crate) struct ControlRequest {
+    pub(crate) seq: usize,
+    pub(crate) action: ControlAction,
+}
+
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+impl ControlRequest {
+    /// The request following this one.
+    pub(crate) fn next(self, action: ControlAction) -> Self {
+        Self {
+            seq: self.seq + 1,
+            action,
+        }
+    }
+}
+
+/// Lifecycle phase of an accordion.
+///
+/// An accordion moves through `Closed` → `Opening` → `Open` → `Closing` → `Closed`.
+/// The transitional phases last until the panel's transition ends, or until `duration`
+/// milliseconds have passed if no transition event arrives.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Phase {
+    #[default]
+    Closed,
+    Opening,
+    Open,
+    Closing,
+}
+
+impl Phase {
+    /// The resting phase for the given expansion state.
+    pub fn at_rest(expanded: bool) -> Self {
+        if expanded {
+            Phase::Open
+        } else {
+            Phase::Closed
+        }
+    }
+
+    /// The phase this one settles into once its transition ends.
+    pub fn settled(&self) -> Self {
+        match self {
+            Phase::Opening => Phase::Open,
+            Phase::Closing => Phase::Closed,
+            phase => *phase,
+        }
+    }
+
+    /// Whether the accordion is open or opening.
+    pub fn is_expanded(&self) -> bool {
+        matches!(self, Phase::Opening | Phase::Open)
+    }
+
+    /// Whether the accordion is between two resting phases.
+    pub fn is_transitioning(&self) -> bool {
+        matches!(self, Phase::Opening | Phase::Closing)
+    }
+
+    /// The phase name, as emitted in the `data-phase` attribute.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Phase::Closed => "closed",
+            Phase::Opening => "opening",
+            Phase::Open => "open",
+            Phase::Closing => "closing",
+        }
+    }
+}
+
+/// Inline `height` declaration animating the panel through the given phase.
+///
+/// `measured` is the height of the panel's content in pixels, once it is known. An open
+/// panel keeps its natural height so that its content can still grow or shrink.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn panel_height(phase: Phase, measured: Option<f64>) -> String {
+    match (phase, measured) {
+        (Phase::Closed, _) | (Phase::Opening, None) => "height: 0px;".to_string(),
+        (Phase::Opening | Phase::Closing, Some(height)) => format!("height: {}px;", height),
+        _ => String::new(),
+    }
+}
+
+/// When an accordion's panel is present in the DOM.
+///
+/// Panels that stay mounted while closed are rendered `hidden` and `inert`, so their content
+/// is neither focusable nor announced by screen readers.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum MountPolicy {
+    /// Mount the panel the first time it opens, then keep it mounted.
+    Lazy,
+    /// Keep the panel mounted at all times, even before it is first opened.
+    Always,
+    /// Mount the panel while it is open and unmount it once it has closed.
+    #[default]
+    UnmountOnClose,
+}
+
+impl MountPolicy {
+    /// Whether the panel is rendered in the given phase.
+    ///
+    /// `opened_before` tells whether the panel has left the `Closed` phase at least once.
+    pub fn is_mounted(&self, phase: Phase, opened_before: bool) -> bool {
+        match self {
+            MountPolicy::Lazy => opened_before || phase != Phase::Closed,
+            MountPolicy::Always => true,
+            MountPolicy::UnmountOnClose => phase != Phase::Closed,
+        }
+    }
+}
+
+/// Event passed to the `will_open` and `will_close` callbacks.
+///
+/// Calling `prevent_default` cancels the toggle, leaving the accordion in its current state.
+#[derive(Clone, Debug, Default)]
+pub struct ToggleEvent {
+    opening: bool,
+    prevented: Arc<AtomicBool>,
+}
+
+impl ToggleEvent {
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    pub(crate) fn new(opening: bool) -> Self {
+        Self {
+            opening,
+            prevented: Arc::default(),
+        }
+    }
+
+    /// Whether the accordion is about to open (`true`) or close (`false`).
+    pub fn is_opening(&self) -> bool {
+        self.opening
+    }
+
+    /// Cancels the toggle.
+    pub fn prevent_default(&self) {
+        self.prevented.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether `prevent_default` has been called.
+    pub fn default_prevented(&self) -> bool {
+        self.prevented.load(Ordering::Relaxed)
+    }
+}
+
+/// Class of an accordion's container, which also carries its `data-phase` attribute.
+pub const ROOT_CLASS: &str = "accordion";
+
+/// Class of the `<h1>`–`<h6>` heading wrapping an accordion's header.
+pub const HEADING_CLASS: &str = "accordion-heading";
+
+/// Class of an accordion's header `<button>`, which carries `data-disabled` while disabled.
+pub const HEADER_CLASS: &str = "accordion-header";
+
+/// Class of the expand/collapse indicator in an accordion's header, which carries `data-expanded`
+/// while expanded and `data-placement` set to `leading` or `trailing`.
+pub const INDICATOR_CLASS: &str = "accordion-indicator";
+
+/// Class of an accordion's collapsible panel.
+pub const PANEL_CLASS: &str = "accordion-panel";
+
+/// Class of an `AccordionGroup`'s container.
+pub const GROUP_CLASS: &str = "accordion-group";
+
+/// Class of a `List`.
+pub const LIST_CLASS: &str = "accordion-list";
+
+/// Class of an `Item`.
+pub const ITEM_CLASS: &str = "accordion-item";
+
+/// Class of the `<span>` wrapping an `Item`'s icon, which carries `data-placement` set to
+/// `leading` or `trailing`.
+pub const ITEM_ICON_CLASS: &str = "accordion-item-icon";
+
+/// Class of a `Button`, including `ExpandAllButton` and `CollapseAllButton`.
+pub const BUTTON_CLASS: &str = "accordion-button";
+
+/// Inline styles of an accordion's container, sizing it from the custom properties its `size` sets.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) const ROOT_STYLE: &str = "width: var(--accordion-width, 30rem); \
+    min-width: var(--accordion-min-width, auto); max-width: var(--accordion-max-width, none); \
+    height: var(--accordion-height, auto);";
+
+/// Inline styles for the heading wrapping an accordion header, so it doesn't change the header's look.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) const HEADING_STYLE: &str = "margin: 0; font-size: inherit; font-weight: inherit;";
+
+/// Inline styles resetting the header `<button>` to a full-width row that inherits its look.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) const HEADER_STYLE: &str = "display: flex; align-items: center; gap: 0.5em; \
+    width: 100%; margin: 0; padding: var(--accordion-header-padding, 0); border: none; \
+    background: var(--accordion-header-background, none); \
+    color: var(--accordion-header-color, inherit); font: inherit; text-align: inherit; \
+    transition: all var(--accordion-duration, 600ms) var(--accordion-easing, ease);";
+
+/// Inline styles of an accordion's panel, clipping its content while the height animates.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) const PANEL_STYLE: &str = "overflow: hidden; \
+    transition: all var(--accordion-duration, 600ms) var(--accordion-easing, ease);";
+
+/// Inline styles of the header indicator, rotated while the accordion is expanded.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn indicator_style(placement: Placement, expanded: bool) -> String {
+    format!(
+        "display: inline-flex; flex: none; \
+        transition: transform var(--accordion-duration, 600ms) var(--accordion-easing, ease);{}{}",
+        if placement == Placement::Trailing {
+            " margin-inline-start: auto;"
+        } else {
+            ""
+        },
+        if expanded {
+            " transform: rotate(var(--accordion-indicator-rotation, 180deg));"
+        } else {
+            ""
+        }
+    )
+}
+
+/// Default look of the components, targeting their stable class names.
+///
+/// Include the returned CSS once, e.g. in a `<style>` element. The rules are wrapped in `:where()`,
+/// so any selector of your own overrides them without `!important`. Sizing, the header reset and
+/// the animations are inline styles, so the stylesheet only covers the look.
+///
+/// The inline styles and the stylesheet read these custom properties, which can be set on any
+/// ancestor:
+///
+/// | Property                         | Default       |
+/// | -------------------------------- | ------------- |
+/// | `--accordion-width`              | `30rem`       |
+/// | `--accordion-min-width`          | `auto`        |
+/// | `--accordion-max-width`          | `none`        |
+/// | `--accordion-height`             | `auto`        |
+/// | `--accordion-duration`           | `600ms`       |
+/// | `--accordion-easing`             | `ease`        |
+/// | `--accordion-padding`            | `0`           |
+/// | `--accordion-color`              | `inherit`     |
+/// | `--accordion-background`         | `transparent` |
+/// | `--accordion-header-padding`     | `0`           |
+/// | `--accordion-header-color`       | `inherit`     |
+/// | `--accordion-header-background`  | `none`        |
+/// | `--accordion-indicator-rotation` | `180deg`      |
+///
+/// The `size` and `duration` props set the matching width, height and duration properties inline
+/// on the accordion's container when given; a responsive size renders its rules in a `<style>`
+/// element instead. Nested accordions without a size reset the width and height properties rather
+/// than inheriting their parent's. The fallback ending a transition uses the `duration` prop, so set it as well
+/// when CSS lengthens `--accordion-duration`.
+pub fn default_stylesheet() -> &'static str {
+    r#":where(.accordion) {
+  box-sizing: border-box;
+  padding: var(--accordion-padding, 0);
+  color: var(--accordion-color, inherit);
+  background: var(--accordion-background, transparent);
+}
+:where(.accordion-item-icon) {
+  display: inline-flex;
+  vertical-align: middle;
+  margin-inline-end: 0.5em;
+}
+:where(.accordion-item-icon[data-placement="trailing"]) {
+  margin-inline: 0.5em 0;
+}
+"#
+}
+
+/// Selector matching the headers of an accordion and its siblings, relative to their shared parent.
+///
+/// Each accordion renders its header `<button>` inside a heading: `parent > accordion > heading > button`.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) const SIBLING_HEADERS: &str = ":scope > * > * > [data-accordion-header]";
+
+/// Navigation keys handled on a focused accordion header, following the WAI-ARIA accordion pattern.
+///
+/// `Enter` and `Space` need no handling, since the header is a native `<button>`.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum HeaderKey {
+    /// `ArrowUp`: focuses the previous header, wrapping to the last one.
+    Previous,
+    /// `ArrowDown`: focuses the next header, wrapping to the first one.
+    Next,
+    /// `Home`: focuses the first header.
+    First,
+    /// `End`: focuses the last header.
+    Last,
+}
+
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+impl HeaderKey {
+    /// Parses a `KeyboardEvent.key` value.
+    pub(crate) fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "ArrowUp" => Some(HeaderKey::Previous),
+            "ArrowDown" => Some(HeaderKey::Next),
+            "Home" => Some(HeaderKey::First),
+            "End" => Some(HeaderKey::Last),
+            _ => None,
+        }
+    }
+
+    /// Index of the header to focus, given the focused one among `count` sibling headers.
+    pub(crate) fn focus_index(self, current: usize, count: usize) -> Option<usize> {
+        if count == 0 {
+            return None;
+        }
+        Some(match self {
+            HeaderKey::Previous => (current + count - 1) % count,
+            HeaderKey::Next => (current + 1) % count,
+            HeaderKey::First => 0,
+            HeaderKey::Last => count - 1,
+        })
+    }
+}
+
+thread_local! {
+    static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Takes the next number from the per-thread accordion id counter.
+///
+/// Accordions take their number once, when they are first rendered, so the ids follow
+/// render order.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn next_id() -> usize {
+    NEXT_ID.with(|next| next.replace(next.get() + 1))
+}
+
+/// Restarts the generated accordion ids from zero.
+///
+/// The browser starts counting from zero on page load. When rendering on the server, call this
+/// before rendering each response so that the ids in the HTML match the ones used while hydrating.
+pub fn reset_ids() {
+    NEXT_ID.with(|next| next.set(0));
+}
+
+/// Ids linking an accordion's header and panel through `aria-controls` and `aria-labelledby`.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct AccordionIds {
+    /// Prefix of the ids generated for nested accordions.
+    pub(crate) base: String,
+    pub(crate) header: String,
+    pub(crate) panel: String,
+}
+
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+impl AccordionIds {
+    /// Uses `aria_controls` as the panel id when it isn't empty, and otherwise derives both ids
+    /// from the `generated` base id.
+    pub(crate) fn new(aria_controls: &str, generated: &str) -> Self {
+        if aria_controls.is_empty() {
+            Self {
+                base: generated.to_string(),
+                header: format!("{generated}-header"),
+                panel: format!("{generated}-panel"),
+            }
+        } else {
+            Self {
+                base: aria_controls.to_string(),
+                header: format!("{aria_controls}-header"),
+                panel: aria_controls.to_string(),
+            }
+        }
+    }
+
+    /// Whether one of the ids an URL links to is the base or panel id of the accordion.
+    pub(crate) fn is_linked(&self, links: &[String]) -> bool {
+        links
+            .iter()
+            .any(|link| *link == self.base || *link == self.panel)
+    }
+
+    /// Generates the base id of an accordion, taking the next number of the id counter for a
+    /// top-level accordion.
+    ///
+    /// A nested accordion appends its index among its siblings to the base id of its parent.
+    pub(crate) fn generate(parent: Option<(&str, usize)>) -> String {
+        match parent {
+            Some((parent, index)) => format!("{parent}-{index}"),
+            None => format!("accordion-{}", next_id()),
+        }
+    }
+}
+
+/// Default heading level of an accordion's header: one below its parent's, or `3` at the top level.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn nested_heading_level(parent: Option<u8>) -> u8 {
+    parent
+        .map_or(3, |level| level.saturating_add(1))
+        .clamp(1, 6)
+}
+
+/// Default indentation class of an accordion nested `depth` levels deep.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn depth_class(depth: usize) -> String {
+    format!("accordion-depth-{depth}")
+}
+
+/// What happens to the accordions nested in a panel when it closes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DescendantPolicy {
+    /// Nested accordions keep their state, even when the closed panel is unmounted.
+    #[default]
+    Remember,
+    /// Nested accordions go back to their `default_expanded` state.
+    Reset,
+}
+
+/// Bookkeeping an accordion keeps about the accordions nested in its panel.
+///
+/// Nested accordions register in render order. Once the panel is unmounted the indices restart,
+/// so the accordions get the same index, and their remembered state, when it mounts again.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+#[derive(Clone, PartialEq, Debug, Default)]
+pub(crate) struct NestedState {
+    next_index: usize,
+    remembered: HashMap<usize, bool>,
+}
+
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+impl NestedState {
+    /// Hands out the index of the next nested accordion.
+    pub(crate) fn register(&mut self) -> usize {
+        let index = self.next_index;
+        self.next_index += 1;
+        index
+    }
+
+    /// Restarts the indices while the panel is unmounted.
+    pub(crate) fn restart(&mut self) {
+        self.next_index = 0;
+    }
+
+    /// Expansion state last recorded for the nested accordion at `index`.
+    pub(crate) fn remembered(&self, index: usize) -> Option<bool> {
+        self.remembered.get(&index).copied()
+    }
+
+    /// Records the expansion state of the nested accordion at `index`.
+    pub(crate) fn remember(&mut self, index: usize, expanded: bool) {
+        self.remembered.insert(index, expanded);
+    }
+
+    /// Forgets every recorded state, when the nested accordions are reset.
+    pub(crate) fn forget(&mut self) {
+        self.remembered.clear();
+    }
+}
+
+/// Web storage used to persist the expansion state of accordions with a `persist_key`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PersistStorage {
+    /// `window.localStorage`: the state survives closing the browser.
+    #[default]
+    Local,
+    /// `window.sessionStorage`: the state lasts for the browser tab's session.
+    Session,
+}
+
+impl PersistStorage {
+    /// Name of the storage object on `window`.
+    #[cfg(feature = "dio")]
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            PersistStorage::Local => "localStorage",
+            PersistStorage::Session => "sessionStorage",
+        }
+    }
+
+    /// The storage object, when the browser provides one and allows access to it.
+    #[cfg(any(feature = "yew", feature = "lep"))]
+    fn storage(self) -> Option<web_sys::Storage> {
+        let window = web_sys::window()?;
+        match self {
+            PersistStorage::Local => window.local_storage(),
+            PersistStorage::Session => window.session_storage(),
+        }
+        .ok()
+        .flatten()
+    }
+
+    /// Reads the value saved under `key`, if any.
+    #[cfg(any(feature = "yew", feature = "lep"))]
+    pub(crate) fn load(self, key: &str) -> Option<String> {
+        self.storage()?.get_item(key).ok().flatten()
+    }
+
+    /// Saves `value` under `key`, ignoring storage that is unavailable or full.
+    #[cfg(any(feature = "yew", feature = "lep"))]
+    pub(crate) fn save(self, key: &str, value: &str) {
+        if let Some(storage) = self.storage() {
+            let _ = storage.set_item(key, value);
+        }
+    }
+}
+
+/// How an accordion reflects its expansion in the page URL.
+///
+/// The URL is rewritten with `history.replaceState`, so the current route and history entry are kept.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum UrlUpdate {
+    /// The URL is only read when the accordion is created.
+    #[default]
+    None,
+    /// Opening sets the hash to the accordion's id, and closing clears it if it still points at the accordion.
+    Hash,
+    /// Opening adds the accordion's id to the comma-separated `open` query parameter, and closing removes it.
+    Query,
+}
+
+impl UrlUpdate {
+    /// The path, query and hash of the URL after the accordion `id` opened or closed, or `None` if they
+    /// don't change.
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    pub(crate) fn apply(
+        self,
+        path: &str,
+        search: &str,
+        hash: &str,
+        id: &str,
+        open: bool,
+    ) -> Option<String> {
+        let (search, hash) = match self {
+            UrlUpdate::None => return None,
+            UrlUpdate::Hash => {
+                let linked = hash.strip_prefix('#') == Some(id);
+                if open == linked {
+                    return None;
+                }
+                let hash = if open {
+                    format!("#{id}")
+                } else {
+                    String::new()
+                };
+                (search.to_string(), hash)
+            }
+            UrlUpdate::Query => {
+                let mut ids = open_param(search);
+                if open == ids.iter().any(|linked| linked == id) {
+                    return None;
+                }
+                if open {
+                    ids.push(id.to_string());
+                } else {
+                    ids.retain(|linked| linked != id);
+                }
+                let mut params: Vec<String> = search
+                    .trim_start_matches('?')
+                    .split('&')
+                    .filter(|param| !param.is_empty() && !param.starts_with("open="))
+                    .map(str::to_string)
+                    .collect();
+                if !ids.is_empty() {
+                    params.push(format!("open={}", ids.join(",")));
+                }
+                let search = if params.is_empty() {
+                    String::new()
+                } else {
+                    format!("?{}", params.join("&"))
+                };
+                (search, hash.to_string())
+            }
+        };
+        Some(format!("{path}{search}{hash}"))
+    }
+
+    /// Rewrites the browser's URL after the accordion `id` opened or closed.
+    #[cfg(any(feature = "yew", feature = "lep"))]
+    pub(crate) fn update_location(self, id: &str, open: bool) {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let location = window.location();
+        let (Ok(path), Ok(search), Ok(hash)) =
+            (location.pathname(), location.search(), location.hash())
+        else {
+            return;
+        };
+        if let (Some(url), Ok(history)) = (
+            self.apply(&path, &search, &hash, id, open),
+            window.history(),
+        ) {
+            let state = history
+                .state()
+                .unwrap_or(web_sys::wasm_bindgen::JsValue::NULL);
+            let _ = history.replace_state_with_url(&state, "", Some(&url));
+        }
+    }
+}
+
+/// Ids listed in the `open` query parameter, separated by commas.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+fn open_param(search: &str) -> Vec<String> {
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .filter_map(|param| param.strip_prefix("open="))
+        .flat_map(|ids| ids.split(',').flat_map(|id| id.split("%2C")))
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Ids an URL links to: its hash, without the leading `#`, and the ids of its `open` query parameter.
+#[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+pub(crate) fn linked_ids(search: &str, hash: &str) -> Vec<String> {
+    let mut ids = open_param(search);
+    if let Some(id) = hash.strip_prefix('#').filter(|id| !id.is_empty()) {
+        ids.push(id.to_string());
+    }
+    ids
+}
+
+/// Ids linked by the browser's current URL.
+#[cfg(any(feature = "yew", feature = "lep"))]
+pub(crate) fn location_links() -> Vec<String> {
+    let Some(location) = web_sys::window().map(|window| window.location()) else {
+        return Vec::new();
+    };
+    linked_ids(
+        &location.search().unwrap_or_default(),
+        &location.hash().unwrap_or_default(),
+    )
+}
+
+/// Scrolls the element with the given id into view, if it exists.
+#[cfg(any(feature = "yew", feature = "lep"))]
+pub(crate) fn scroll_into_view(id: &str) {
+    if let Some(element) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(id))
+    {
+        element.scroll_into_view();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn set_all_opens_every_live_key() {
+        let mut state = GroupState::default();
+        state.set_all(&[0, 2, 3], true, false);
+        assert!(state.is_open(0) && state.is_open(2) && state.is_open(3));
+        assert!(!state.is_open(1));
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn set_all_opens_only_the_first_live_key_in_single_mode() {
+        let mut state = GroupState::default();
+        state.set_all(&[1, 2], true, true);
+        assert!(state.is_open(1));
+        assert!(!state.is_open(2));
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn set_all_closes_every_key() {
+        let mut state = GroupState::default();
+        state.set_all(&[0, 1], true, false);
+        state.set_all(&[0, 1], false, false);
+        assert_eq!(state.status(&[0, 1]), GroupStatus::NoneOpen);
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn status_reports_all_none_and_some_open() {
+        let mut state = GroupState::default();
+        assert_eq!(state.status(&[]), GroupStatus::NoneOpen);
+        assert_eq!(state.status(&[0, 1]), GroupStatus::NoneOpen);
+        state.open(0, false);
+        assert_eq!(state.status(&[0, 1]), GroupStatus::SomeOpen);
+        state.open(1, false);
+        assert_eq!(state.status(&[0, 1]), GroupStatus::AllOpen);
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn status_ignores_unregistered_keys() {
+        let mut members = GroupMembers::default();
+        let first = members.register();
+        let second = members.register();
+        let mut state = GroupState::default();
+        state.open(first, false);
+        members.unregister(second);
+        assert_eq!(state.status(&members.keys()), GroupStatus::AllOpen);
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn encode_decode_round_trips_positions_of_live_keys() {
+        let mut state = GroupState::default();
+        state.open(1, false);
+        state.open(4, false);
+        let keys = [1, 3, 4];
+        let encoded = state.encode(&keys);
+        assert_eq!(encoded, "0,2");
+        assert_eq!(GroupState::decode(&encoded, &keys), state);
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn decode_skips_malformed_and_unknown_positions() {
+        let state = GroupState::decode(" 1 ,x,,-2,7,1", &[5, 6]);
+        assert!(state.is_open(6));
+        assert!(!state.is_open(5));
+        assert_eq!(state.encode(&[5, 6]), "1");
+        assert_eq!(GroupState::decode("", &[5, 6]), GroupState::default());
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn hash_update_links_and_unlinks_the_accordion() {
+        let update = UrlUpdate::Hash;
+        assert_eq!(
+            update.apply("/docs", "?tab=1", "", "faq", true).as_deref(),
+            Some("/docs?tab=1#faq")
+        );
+        assert_eq!(
+            update
+                .apply("/docs", "?tab=1", "#faq", "faq", false)
+                .as_deref(),
+            Some("/docs?tab=1")
+        );
+        assert_eq!(update.apply("/docs", "", "#faq", "faq", true), None);
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn hash_update_keeps_unrelated_fragments_on_close() {
+        let update = UrlUpdate::Hash;
+        assert_eq!(update.apply("/docs", "", "#intro", "faq", false), None);
+        assert_eq!(
+            update.apply("/docs", "", "#intro", "faq", true).as_deref(),
+            Some("/docs#faq")
+        );
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn query_update_rewrites_only_the_open_param() {
+        let update = UrlUpdate::Query;
+        assert_eq!(
+            update
+                .apply("/docs", "?tab=1", "#intro", "faq", true)
+                .as_deref(),
+            Some("/docs?tab=1&open=faq#intro")
+        );
+        assert_eq!(
+            update
+                .apply("/docs", "?open=intro&tab=1", "", "faq", true)
+                .as_deref(),
+            Some("/docs?tab=1&open=intro,faq")
+        );
+        assert_eq!(
+            update
+                .apply("/docs", "?tab=1&open=intro,faq", "#top", "faq", false)
+                .as_deref(),
+            Some("/docs?tab=1&open=intro#top")
+        );
+        assert_eq!(
+            update
+                .apply("/docs", "?open=faq", "", "faq", false)
+                .as_deref(),
+            Some("/docs")
+        );
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn query_update_skips_unchanged_urls() {
+        let update = UrlUpdate::Query;
+        assert_eq!(update.apply("/", "?open=a,faq", "", "faq", true), None);
+        assert_eq!(update.apply("/", "?tab=1", "", "faq", false), None);
+        assert_eq!(UrlUpdate::None.apply("/", "", "", "faq", true), None);
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn linked_ids_reads_the_open_param_and_hash() {
+        assert_eq!(linked_ids("?open=a,b&tab=1", "#c"), ["a", "b", "c"]);
+        assert_eq!(linked_ids("?tab=1&open=a%2Cb", ""), ["a", "b"]);
+        assert_eq!(linked_ids("?open=,a,", "#"), ["a"]);
+        assert!(linked_ids("?opened=a&xopen=b", "").is_empty());
+    }
+
+    #[test]
+    fn length_parses_units_whitespace_signs_and_decimals() {
+        assert_eq!("20rem".parse(), Ok(Length::Rem(20.0)));
+        assert_eq!(" 1.5em ".parse(), Ok(Length::Em(1.5)));
+        assert_eq!("-4px".parse(), Ok(Length::Px(-4.0)));
+        assert_eq!("+2vw".parse(), Ok(Length::Vw(2.0)));
+        assert_eq!(".5rem".parse(), Ok(Length::Rem(0.5)));
+        assert_eq!("50%".parse(), Ok(Length::Percent(50.0)));
+        assert_eq!("0".parse(), Ok(Length::Px(0.0)));
+        assert_eq!(
+            "calc(100% - (2rem + 1px))".parse(),
+            Ok(Length::calc("100% - (2rem + 1px)"))
+        );
+    }
+
+    #[test]
+    fn length_rejects_malformed_strings() {
+        let parse = |s: &str| s.parse::<Length>();
+        assert_eq!(parse(""), Err(ParseLengthError::Empty));
+        assert_eq!(parse("  "), Err(ParseLengthError::Empty));
+        assert_eq!(
+            parse("20 rem"),
+            Err(ParseLengthError::UnknownUnit(" rem".to_string()))
+        );
+        assert_eq!(
+            parse("20pt"),
+            Err(ParseLengthError::UnknownUnit("pt".to_string()))
+        );
+        assert_eq!(
+            parse("20"),
+            Err(ParseLengthError::UnknownUnit(String::new()))
+        );
+        assert_eq!(
+            parse("rem"),
+            Err(ParseLengthError::InvalidNumber("rem".to_string()))
+        );
+        assert_eq!(
+            parse("1.2.3px"),
+            Err(ParseLengthError::InvalidNumber("1.2.3px".to_string()))
+        );
+        for calc in ["calc()", "calc((1px)", "calc(1px))"] {
+            assert_eq!(
+                parse(calc),
+                Err(ParseLengthError::InvalidCalc(calc.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn length_arithmetic_folds_matching_units() {
+        assert_eq!(Length::px(10.0) + Length::px(5.0), Length::Px(15.0));
+        assert_eq!(Length::rem(2.0) - Length::rem(0.5), Length::Rem(1.5));
+        assert_eq!(Length::rem(1.5) * 2.0, Length::Rem(3.0));
+        assert_eq!(Length::percent(90.0) / 3.0, Length::Percent(30.0));
+    }
+
+    #[test]
+    fn length_arithmetic_mixes_units_in_calc() {
+        let width = Length::percent(100.0) - Length::rem(2.0);
+        assert_eq!(width.to_string(), "calc(100% - 2rem)");
+        assert_eq!(
+            (width.clone() + Length::px(4.0)).to_string(),
+            "calc((100% - 2rem) + 4px)"
+        );
+        assert_eq!((width / 2.0).to_string(), "calc((100% - 2rem) / 2)");
+    }
+
+    #[test]
+    fn length_arithmetic_never_produces_non_finite_values() {
+        assert_eq!(Length::px(10.0) / 0.0, Length::Px(0.0));
+        assert_eq!(Length::rem(1.0) * f32::INFINITY, Length::Rem(0.0));
+        assert_eq!(Length::em(1.0) * f32::NAN, Length::Em(0.0));
+        assert_eq!(Length::vw(f32::MAX) * 2.0, Length::Vw(0.0));
+        assert_eq!(Length::px(f32::MAX) + Length::px(f32::MAX), Length::Px(0.0));
+        assert_eq!(Length::calc("100% - 2rem") / 0.0, Length::Px(0.0));
+        assert_eq!((Length::px(1.0) / 0.0).to_string(), "0px");
+    }
+
+    const FULL: &str = "--accordion-width: 100%; --accordion-min-width: auto; \
+                        --accordion-max-width: none; --accordion-height: auto; ";
+    const MAX_WIDTH: &str = "--accordion-width: 100%; --accordion-min-width: auto; \
+                             --accordion-max-width: 40rem; --accordion-height: auto; ";
+
+    #[test]
+    fn size_declarations_reset_unset_bounds() {
+        assert_eq!(Size::Full.declarations(), FULL);
+        assert_eq!(Size::MaxWidth(Length::rem(40.0)).declarations(), MAX_WIDTH);
+        let fluid = Size::Fluid {
+            min: Length::rem(20.0),
+            max: Length::rem(60.0),
+        };
+        assert_eq!(
+            fluid.declarations(),
+            "--accordion-width: 100%; --accordion-min-width: 20rem; \
+             --accordion-max-width: 60rem; --accordion-height: auto; "
+        );
+    }
+
+    #[test]
+    fn size_to_css_adds_a_rule_per_breakpoint() {
+        assert_eq!(Size::Full.to_css(".faq"), format!(".faq {{ {FULL}}}\n"));
+        let media: Size = ResponsiveSize::new(Size::Full)
+            .at(Breakpoint::Md, Size::MaxWidth(Length::rem(40.0)))
+            .into();
+        assert_eq!(
+            media.to_css(".faq"),
+            format!(".faq {{ {FULL}}}\n@media (min-width: 768px) {{ .faq {{ {MAX_WIDTH}}} }}\n")
+        );
+        let container: Size = ResponsiveSize::container(Size::Full)
+            .at(
+                Breakpoint::Custom(Length::em(50.0)),
+                Size::MaxWidth(Length::rem(40.0)),
+            )
+            .into();
+        assert_eq!(
+            container.to_css(".faq"),
+            format!(".faq {{ {FULL}}}\n@container (min-width: 50em) {{ .faq {{ {MAX_WIDTH}}} }}\n")
+        );
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn properties_declare_inline_sizes() {
+        let config = AccordionConfig::default();
+        assert_eq!(config.properties(None, None, false), "");
+        assert_eq!(
+            config.properties(None, None, true),
+            Size::default().declarations()
+        );
+        assert_eq!(
+            config.properties(Some(250), Some(&Size::Full), false),
+            format!("--accordion-duration: 250ms; {FULL}")
+        );
+        let provided = AccordionConfig {
+            size: Some(Size::MaxWidth(Length::rem(40.0))),
+            ..AccordionConfig::default()
+        };
+        assert_eq!(provided.properties(None, None, true), MAX_WIDTH);
+        assert_eq!(provided.properties(None, Some(&Size::Full), false), FULL);
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn properties_leave_responsive_sizes_to_style_rules() {
+        let config = AccordionConfig::default();
+        let size: Size = ResponsiveSize::new(Size::Full)
+            .at(Breakpoint::Md, Size::MaxWidth(Length::rem(40.0)))
+            .into();
+        assert_eq!(config.properties(None, Some(&size), true), "");
+        assert_eq!(
+            config.size_rules(Some(&size), "faq"),
+            Some(size.to_css("[data-accordion-id=\"faq\"]"))
+        );
+        assert_eq!(config.size_rules(Some(&Size::Full), "faq"), None);
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn focus_index_wraps_around_the_ends() {
+        assert_eq!(HeaderKey::Next.focus_index(1, 3), Some(2));
+        assert_eq!(HeaderKey::Next.focus_index(2, 3), Some(0));
+        assert_eq!(HeaderKey::Previous.focus_index(1, 3), Some(0));
+        assert_eq!(HeaderKey::Previous.focus_index(0, 3), Some(2));
+        assert_eq!(HeaderKey::Next.focus_index(0, 1), Some(0));
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn focus_index_jumps_to_the_first_and_last_header() {
+        assert_eq!(HeaderKey::from_key("Home"), Some(HeaderKey::First));
+        assert_eq!(HeaderKey::from_key("End"), Some(HeaderKey::Last));
+        assert_eq!(HeaderKey::from_key("ArrowLeft"), None);
+        assert_eq!(HeaderKey::First.focus_index(2, 4), Some(0));
+        assert_eq!(HeaderKey::Last.focus_index(0, 4), Some(3));
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn focus_index_is_none_without_headers() {
+        for key in [
+            HeaderKey::Previous,
+            HeaderKey::Next,
+            HeaderKey::First,
+            HeaderKey::Last,
+        ] {
+            assert_eq!(key.focus_index(0, 0), None);
+        }
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn accordion_ids_prefer_aria_controls() {
+        let ids = AccordionIds::new("faq-shipping", "accordion-3");
+        assert_eq!(ids.base, "faq-shipping");
+        assert_eq!(ids.header, "faq-shipping-header");
+        assert_eq!(ids.panel, "faq-shipping");
+        let ids = AccordionIds::new("", "accordion-3");
+        assert_eq!(ids.base, "accordion-3");
+        assert_eq!(ids.header, "accordion-3-header");
+        assert_eq!(ids.panel, "accordion-3-panel");
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn accordion_ids_number_top_level_and_nested_accordions() {
+        reset_ids();
+        assert_eq!(AccordionIds::generate(None), "accordion-0");
+        assert_eq!(AccordionIds::generate(None), "accordion-1");
+        assert_eq!(
+            AccordionIds::generate(Some(("accordion-1", 0))),
+            "accordion-1-0"
+        );
+        assert_eq!(
+            AccordionIds::generate(Some(("faq-shipping-2", 1))),
+            "faq-shipping-2-1"
+        );
+        reset_ids();
+        assert_eq!(AccordionIds::generate(None), "accordion-0");
+    }
+
+    #[cfg(any(feature = "yew", feature = "dio", feature = "lep"))]
+    #[test]
+    fn accordion_ids_link_by_base_or_panel_id() {
+        let links = |ids: &[&str]| ids.iter().map(ToString::to_string).collect::<Vec<_>>();
+        let ids = AccordionIds::new("", "accordion-0-1");
+        assert!(ids.is_linked(&links(&["accordion-0-1"])));
+        assert!(ids.is_linked(&links(&["other", "accordion-0-1-panel"])));
+        assert!(!ids.is_linked(&links(&["accordion-0-1-header", "accordion-0"])));
+        assert!(!ids.is_linked(&[]));
+    }
+}
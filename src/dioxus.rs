@@ -1,5 +1,16 @@
-use crate::common::{Align, Size};
+use crate::common::{
+    depth_class, indicator_label, indicator_style, linked_ids, panel_height, AccordionConfig,
+    AccordionIds, Align, ControlAction, ControlRequest, DescendantPolicy, GroupMembers, GroupState,
+    GroupStatus, HeaderKey, MountPolicy, NestedState, PersistStorage, Phase, Placement, Size,
+    Theme, ToggleEvent, UrlUpdate, BUTTON_CLASS, GROUP_CLASS, HEADER_CLASS, HEADER_STYLE,
+    HEADING_CLASS, HEADING_STYLE, INDICATOR_CLASS, ITEM_CLASS, ITEM_ICON_CLASS, LIST_CLASS,
+    PANEL_CLASS, PANEL_STYLE, ROOT_CLASS, ROOT_STYLE, SIBLING_HEADERS,
+};
 use dioxus::prelude::*;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use web_sys::wasm_bindgen::JsCast;
 
 /// Properties for the Accordion component.
 #[derive(Props, PartialEq, Clone)]
@@ -7,8 +18,24 @@ pub struct AccordionProps {
     /// A signal that manages the expansion state of the accordion.
     ///
     /// This property determines whether the accordion is expanded (`true`) or collapsed (`false`).
-    /// It is required and is used to toggle the accordion's state.
-    pub expand: Signal<bool>,
+    /// When omitted, the accordion keeps its own state, or follows its `AccordionGroup` if it has one.
+    #[props(default)]
+    pub expand: Option<Signal<bool>>,
+
+    /// A controller handle created by `use_accordion`.
+    ///
+    /// Replaces `expand`: the accordion follows the handle's state, and the handle's `open`, `close`
+    /// and `toggle` run like a header click, firing `will_open`/`will_close` and `did_open`/`did_close`.
+    /// Defaults to `None`.
+    #[props(default)]
+    pub handle: Option<AccordionHandle>,
+
+    /// Whether an uncontrolled accordion starts expanded.
+    ///
+    /// Only read when the accordion is created, and only when `expand` is not provided.
+    /// Defaults to `false`.
+    #[props(default)]
+    pub default_expanded: bool,
 
     /// The content to display when the accordion is expanded.
     ///
@@ -28,105 +55,241 @@ pub struct AccordionProps {
     /// Size of the accordion.
     ///
     /// Defines the size of the accordion component. Typically used for layout adjustments.
-    /// Defaults to an `XXLarge` if not specified.
+    /// Defaults to the `AccordionConfigProvider`'s size, or `XXLarge` if neither is specified.
     #[props(default)]
-    pub size: Size,
+    pub size: Option<Size>,
 
     /// ARIA controls attribute for accessibility.
     ///
     /// Links the accordion container to another element, improving accessibility for screen readers.
-    /// Defaults to an empty string.
-    #[props(default = "")]
-    pub aria_controls: &'static str,
+    /// It is used as the panel's `id`, and the header's `id` appends `-header` to it.
+    /// Defaults to an empty string, which generates unique ids.
+    #[props(default, into)]
+    pub aria_controls: String,
+
+    /// Shared classes and styles for the accordion, its lists and items.
+    ///
+    /// The instance's own class and style props override the theme's values when they aren't
+    /// empty. Defaults to the parent accordion's theme, then the `AccordionConfigProvider`'s, if any.
+    #[props(default)]
+    pub theme: Option<Theme>,
 
     /// Custom inline styles for the accordion container.
     ///
     /// Specifies additional CSS styling for the accordion's container. Defaults to an empty string.
-    #[props(default = "")]
-    pub style: &'static str,
+    #[props(default, into)]
+    pub style: String,
 
     /// Custom inline styles for the expanded state.
     ///
     /// Defines additional styling for the accordion when it is expanded. Defaults to an empty string.
-    #[props(default = "")]
-    pub expanded_style: &'static str,
+    #[props(default, into)]
+    pub expanded_style: String,
 
     /// Custom inline styles for the collapsed state.
     ///
     /// Defines additional styling for the accordion when it is collapsed. Defaults to an empty string.
-    #[props(default = "")]
-    pub collapsed_style: &'static str,
+    #[props(default, into)]
+    pub collapsed_style: String,
+
+    /// Custom inline styles for the disabled state.
+    ///
+    /// Appended to the header's styles while `disabled` is `true`. Defaults to an empty string.
+    #[props(default, into)]
+    pub disabled_style: String,
 
     /// Custom inline styles for the content section.
     ///
     /// Specifies additional styling for the content within the accordion. Defaults to an empty string.
-    #[props(default = "")]
-    pub content_style: &'static str,
+    #[props(default, into)]
+    pub content_style: String,
 
     /// Custom CSS class for the accordion container.
     ///
     /// Applies a custom class to the container element for styling purposes. Defaults to an empty string.
-    #[props(default = "")]
-    pub class: &'static str,
+    #[props(default, into)]
+    pub class: String,
 
     /// Custom CSS class for the expanded state.
     ///
     /// Specifies a class that is applied to the accordion when it is in an expanded state. Defaults to an empty string.
-    #[props(default = "")]
-    pub expanded_class: &'static str,
+    #[props(default, into)]
+    pub expanded_class: String,
 
     /// Custom CSS class for the collapsed state.
     ///
     /// Specifies a class that is applied to the accordion when it is in a collapsed state. Defaults to an empty string.
-    #[props(default = "")]
-    pub collapsed_class: &'static str,
+    #[props(default, into)]
+    pub collapsed_class: String,
+
+    /// Custom CSS class for the disabled state.
+    ///
+    /// Added to the header next to the expanded or collapsed class while `disabled` is `true`. Defaults to an empty string.
+    #[props(default, into)]
+    pub disabled_class: String,
 
     /// Custom CSS class for the content container.
     ///
     /// Applies a class to the accordion's content container for styling purposes. Defaults to an empty string.
-    #[props(default = "")]
-    pub content_class: &'static str,
+    #[props(default, into)]
+    pub content_class: String,
 
     /// Indicates whether ARIA attributes should be included.
     ///
     /// If `true`, ARIA attributes such as `aria-expanded` and `aria-controls` will be added for accessibility.
-    /// Defaults to `true`.
-    #[props(default = true)]
-    pub aria_enabled: bool,
+    /// Defaults to the `AccordionConfigProvider`'s value, or `true`.
+    #[props(default)]
+    pub aria_enabled: Option<bool>,
+
+    /// Level of the heading wrapping the header button.
+    ///
+    /// The header is rendered as `h1`–`h6`, with values outside `1..=6` clamped. Defaults to one level
+    /// below the parent accordion's heading, or for a top-level accordion the `AccordionConfigProvider`'s
+    /// level, or `3`.
+    #[props(default)]
+    pub heading_level: Option<u8>,
+
+    /// Indentation class for the accordion container.
+    ///
+    /// Defaults to `accordion-depth-N`, where `N` is the nesting depth: `0` for a top-level accordion.
+    #[props(default, into)]
+    pub indent_class: Option<String>,
+
+    /// What happens to accordions nested in the panel when it closes.
+    ///
+    /// Defaults to `DescendantPolicy::Remember`, which keeps the state of uncontrolled nested
+    /// accordions even when the panel is unmounted.
+    #[props(default)]
+    pub descendants: DescendantPolicy,
 
     /// Duration of the expand/collapse animation in milliseconds.
     ///
-    /// Specifies how long the transition animation should take. Defaults to `600` milliseconds.
-    #[props(default = 600)]
-    pub duration: u64,
+    /// Specifies how long the transition animation should take. Defaults to the
+    /// `AccordionConfigProvider`'s duration, or `600` milliseconds.
+    #[props(default)]
+    pub duration: Option<u64>,
+
+    /// Whether the header ignores clicks.
+    ///
+    /// A disabled accordion keeps its current expand state and sets `aria-disabled`. Defaults to `false`.
+    #[props(default)]
+    pub disabled: bool,
+
+    /// Custom expand/collapse indicator shown in the header.
+    ///
+    /// Rotated along with the built-in one while the accordion is expanded. Defaults to `None`, which
+    /// renders a chevron.
+    #[props(default)]
+    pub indicator: Option<Element>,
+
+    /// Side of the header content on which the indicator is shown.
+    ///
+    /// Defaults to `Placement::Trailing`.
+    #[props(default = Placement::Trailing)]
+    pub indicator_placement: Placement,
+
+    /// Whether the header shows no indicator.
+    ///
+    /// Defaults to `false`.
+    #[props(default)]
+    pub hide_indicator: bool,
+
+    /// Accessible label of the indicator.
+    ///
+    /// Defaults to an empty string, which labels it "Expand" or "Collapse" depending on the state.
+    #[props(default, into)]
+    pub indicator_label: String,
+
+    /// When the content container is present in the DOM.
+    ///
+    /// Closed panels that stay mounted are rendered `hidden` and `inert`.
+    /// Defaults to `MountPolicy::UnmountOnClose`.
+    #[props(default)]
+    pub mount: MountPolicy,
+
+    /// Web storage key under which the expanded state is saved and restored.
+    ///
+    /// The saved state is restored after the first render, so server-rendered HTML always starts from
+    /// `default_expanded`. Nothing is persisted when storage is unavailable. Defaults to `None`.
+    #[props(default, into)]
+    pub persist_key: Option<String>,
+
+    /// Web storage used with `persist_key`.
+    ///
+    /// Defaults to `PersistStorage::Local`.
+    #[props(default)]
+    pub persist_storage: PersistStorage,
+
+    /// Whether the accordion opens when the page URL links to it.
+    ///
+    /// The accordion opens and scrolls its header into view when its id, `aria_controls` or the
+    /// generated one, is the URL's hash or is listed in the comma-separated `open` query parameter.
+    /// The hash is also followed when it changes. Defaults to `false`.
+    #[props(default)]
+    pub deep_link: bool,
+
+    /// How opening and closing the accordion from its header rewrites the page URL.
+    ///
+    /// Defaults to `UrlUpdate::None`.
+    #[props(default)]
+    pub update_url: UrlUpdate,
+
+    /// Async loader for additional panel content.
+    ///
+    /// Runs through `use_resource` the first time the accordion opens, and its output is rendered
+    /// after `children`. The result is kept for later openings. Defaults to `None`.
+    #[props(default)]
+    pub loader: Option<PanelLoader>,
+
+    /// Placeholder shown while the `loader` is running.
+    ///
+    /// Defaults to an empty element.
+    #[props(default = VNode::empty())]
+    pub loading: Element,
+
+    /// View rendered when the `loader` fails.
+    ///
+    /// Receives the error message and a `retry` callback.
+    /// Defaults to a `role="alert"` message with a retry button.
+    #[props(default)]
+    pub error: Option<Callback<PanelError, Element>>,
 
     /// Callback executed before the accordion expands.
     ///
     /// This callback is triggered just before the accordion transitions to an expanded state.
+    /// Calling `prevent_default` on the `ToggleEvent` keeps the accordion closed.
     /// Defaults to a no-op.
     #[props(default)]
-    pub will_open: Callback<()>,
+    pub will_open: Callback<ToggleEvent>,
 
     /// Callback executed after the accordion has expanded.
     ///
-    /// This callback is triggered once the accordion has completed its transition to an expanded state.
-    /// Defaults to a no-op.
+    /// This callback is triggered once the panel's transition has ended, or after `duration`
+    /// milliseconds if no transition runs. Defaults to a no-op.
     #[props(default)]
     pub did_open: Callback<()>,
 
     /// Callback executed before the accordion collapses.
     ///
     /// This callback is triggered just before the accordion transitions to a collapsed state.
+    /// Calling `prevent_default` on the `ToggleEvent` keeps the accordion open.
     /// Defaults to a no-op.
     #[props(default)]
-    pub will_close: Callback<()>,
+    pub will_close: Callback<ToggleEvent>,
 
     /// Callback executed after the accordion has collapsed.
     ///
-    /// This callback is triggered once the accordion has completed its transition to a collapsed state.
-    /// Defaults to a no-op.
+    /// This callback is triggered once the panel's transition has ended, or after `duration`
+    /// milliseconds if no transition runs. Defaults to a no-op.
     #[props(default)]
     pub did_close: Callback<()>,
+
+    /// Callback executed whenever the accordion enters a new lifecycle phase.
+    ///
+    /// Receives the new `Phase` (`Opening`, `Open`, `Closing` or `Closed`).
+    /// Defaults to a no-op.
+    #[props(default)]
+    pub on_phase_change: Callback<Phase>,
 }
 
 /// Accordion Component
@@ -138,32 +301,79 @@ pub struct AccordionProps {
 /// # Properties
 /// The component uses the `AccordionProps` struct for configuration. Key properties include:
 ///
-/// - **expand**: A `Signal<bool>` that controls the expansion state of the accordion. It is required to toggle between expanded and collapsed states.
+/// - **expand**: An optional `Signal<bool>` that controls the expansion state of the accordion. Inside an `AccordionGroup`, the group owns the state instead. Default: `None`.
+/// - **handle**: A controller handle from `use_accordion`, driving the accordion through its callbacks (`Option<AccordionHandle>`). Default: `None`.
+/// - **default_expanded**: Whether an uncontrolled accordion starts expanded (`bool`). Default: `false`.
 /// - **expanded**: The content that is displayed when the accordion is expanded (`Element`). Default: `""`.
 /// - **collapsed**: The content that is displayed when the accordion is collapsed (`Element`). Default: `""`.
 /// - **children**: The child elements to display inside the accordion when expanded (`Element`). Default: `""`.
-/// - **size**: Defines the size of the accordion (`Size`). Default: `Size::XXLarge`.
-/// - **aria_controls**: The ARIA controls attribute for accessibility (`&'static str`). Default: `""`.
-/// - **style**: Inline styles for the accordion container (`&'static str`). Default: `""`.
-/// - **expanded_style**: Inline styles for the expanded content (`&'static str`). Default: `""`.
-/// - **collapsed_style**: Inline styles for the collapsed content (`&'static str`). Default: `""`.
-/// - **content_style**: Inline styles for the content section (`&'static str`). Default: `""`.
-/// - **class**: Custom CSS class for the accordion container (`&'static str`). Default: `""`.
-/// - **expanded_class**: Custom CSS class for the expanded content (`&'static str`). Default: `""`.
-/// - **collapsed_class**: Custom CSS class for the collapsed content (`&'static str`). Default: `""`.
-/// - **content_class**: Custom CSS class for the content section (`&'static str`). Default: `""`.
-/// - **aria_enabled**: If `true`, ARIA attributes will be added to the HTML structure for better accessibility (`bool`). Default: `true`.
-/// - **duration**: Duration of the expand/collapse animation in milliseconds (`u64`). Default: `600`.
-/// - **will_open**: Callback invoked before the accordion expands (`Callback<()>`). Default: no-op.
+/// - **size**: Defines the size of the accordion (`Option<Size>`). Default: the provided size, or `Size::XXLarge`.
+/// - **aria_controls**: The ARIA controls attribute and panel id (`String`). Default: `""`, generating a unique id.
+/// - **theme**: Shared classes and styles, overridden by the props below (`Option<Theme>`). Default: the parent accordion's or provided theme.
+/// - **style**: Inline styles for the accordion container (`String`). Default: `""`.
+/// - **expanded_style**: Inline styles for the expanded content (`String`). Default: `""`.
+/// - **collapsed_style**: Inline styles for the collapsed content (`String`). Default: `""`.
+/// - **disabled_style**: Inline styles for the header while disabled (`String`). Default: `""`.
+/// - **content_style**: Inline styles for the content section (`String`). Default: `""`.
+/// - **class**: Custom CSS class for the accordion container (`String`). Default: `""`.
+/// - **expanded_class**: Custom CSS class for the expanded content (`String`). Default: `""`.
+/// - **collapsed_class**: Custom CSS class for the collapsed content (`String`). Default: `""`.
+/// - **disabled_class**: Custom CSS class for the header while disabled (`String`). Default: `""`.
+/// - **content_class**: Custom CSS class for the content section (`String`). Default: `""`.
+/// - **aria_enabled**: If `true`, ARIA attributes will be added to the HTML structure for better accessibility (`Option<bool>`). Default: the provided value, or `true`.
+/// - **heading_level**: Level of the heading wrapping the header button, from `1` to `6` (`Option<u8>`). Default: one below the parent accordion's, the provided level, or `3`.
+/// - **indent_class**: Indentation class for the accordion container (`Option<String>`). Default: `accordion-depth-N` for nesting depth `N`.
+/// - **descendants**: Whether nested accordions are remembered or reset when the panel closes (`DescendantPolicy`). Default: `DescendantPolicy::Remember`.
+/// - **duration**: Duration of the expand/collapse animation in milliseconds (`Option<u64>`). Default: the provided duration, or `600`.
+/// - **disabled**: Whether the header ignores clicks while keeping the current state (`bool`). Default: `false`.
+/// - **indicator**: Custom expand/collapse indicator, rotated with the state (`Option<Element>`). Default: a chevron.
+/// - **indicator_placement**: Side of the header content on which the indicator is shown (`Placement`). Default: `Placement::Trailing`.
+/// - **hide_indicator**: Whether the header shows no indicator (`bool`). Default: `false`.
+/// - **indicator_label**: Accessible label of the indicator (`String`). Default: `""`, labelling it "Expand" or "Collapse".
+/// - **mount**: When the content container is present in the DOM (`MountPolicy`). Default: `MountPolicy::UnmountOnClose`.
+/// - **persist_key**: Web storage key saving and restoring the expanded state (`Option<String>`). Default: `None`.
+/// - **persist_storage**: Web storage used with `persist_key` (`PersistStorage`). Default: `PersistStorage::Local`.
+/// - **deep_link**: Whether the accordion opens when the URL's hash or `open` query parameter names its id (`bool`). Default: `false`.
+/// - **update_url**: How toggling the accordion rewrites the page URL (`UrlUpdate`). Default: `UrlUpdate::None`.
+/// - **loader**: Async loader for panel content, run on first expand (`Option<PanelLoader>`). Default: `None`.
+/// - **loading**: Placeholder shown while the loader runs (`Element`). Default: empty.
+/// - **error**: View rendered when the loader fails (`Option<Callback<PanelError, Element>>`). Default: alert with a retry button.
+/// - **will_open**: Callback invoked before the accordion expands; can cancel it (`Callback<ToggleEvent>`). Default: no-op.
 /// - **did_open**: Callback invoked after the accordion has expanded (`Callback<()>`). Default: no-op.
-/// - **will_close**: Callback invoked before the accordion collapses (`Callback<()>`). Default: no-op.
+/// - **will_close**: Callback invoked before the accordion collapses; can cancel it (`Callback<ToggleEvent>`). Default: no-op.
 /// - **did_close**: Callback invoked after the accordion has collapsed (`Callback<()>`). Default: no-op.
+/// - **on_phase_change**: Callback invoked when the lifecycle phase changes (`Callback<Phase>`). Default: no-op.
 ///
 /// # Features
 /// - Smooth transitions between expanded and collapsed states with configurable duration.
-/// - Supports ARIA attributes for accessibility.
+/// - Supports ARIA attributes for accessibility. The header and panel get ids, generated from a counter when
+///   `aria_controls` is empty; the panel has `role="region"` and `aria-labelledby` pointing at the header.
+///   Call `reset_ids` before each server render to keep generated ids stable through hydration.
+/// - A header `button` inside an `h1`–`h6` heading chosen by `heading_level`, following the WAI-ARIA
+///   accordion pattern.
+/// - Nesting awareness: accordions inside a panel read the parent's `AccordionParent` context, and their depth
+///   defaults the heading level, indentation class and generated ids. They join an `AccordionGroup` only at
+///   their own depth. Uncontrolled nested accordions keep their state while the parent is closed, or go back
+///   to `default_expanded` with `DescendantPolicy::Reset`.
+/// - Headers follow the WAI-ARIA accordion keyboard pattern: `Enter`/`Space` toggles,
+///   `ArrowUp`/`ArrowDown` move focus to the previous/next sibling accordion header (wrapping), and
+///   `Home`/`End` move it to the first/last one.
 /// - Customizable styles and classes for each section of the accordion.
-/// - Optional callbacks for pre- and post-expansion and collapse events.
+/// - Optional callbacks for pre- and post-expansion and collapse events. `will_open` and `will_close` receive a
+///   `ToggleEvent` whose `prevent_default` cancels the toggle.
+/// - A `Closed` → `Opening` → `Open` → `Closing` → `Closed` lifecycle: `did_open` and `did_close` fire on the
+///   panel's `transitionend`, or after `duration` milliseconds as a fallback. The current phase is reported
+///   through `on_phase_change` and the container's `data-phase` attribute.
+/// - The panel's height is measured and animated between `0` and its content height over `duration` milliseconds.
+/// - A `mount` policy deciding whether a closed panel is unmounted or kept `hidden` and `inert`, preserving its state.
+/// - With a `persist_key`, the expanded state is saved to web storage and restored after the first render.
+///   Without storage, for instance during SSR, the accordion behaves as if no key were given.
+/// - With `deep_link`, an accordion linked by the URL's hash or `open` query parameter opens like a header click and scrolls into view;
+///   `update_url` keeps the URL in sync through `history.replaceState`, leaving the path and the history state untouched.
+/// - Async panel content: a `loader` runs through `use_resource` on first expand, showing `loading` meanwhile and
+///   `error`, with a retry callback, if it fails.
+/// - A `disabled` state that ignores header clicks, keeps the expand state, and sets `aria-disabled`
+///   along with `disabled_class`/`disabled_style`.
 ///
 /// # Examples
 ///
@@ -187,6 +397,22 @@ pub struct AccordionProps {
 /// }
 /// ```
 ///
+/// ## Uncontrolled Accordion
+/// ```rust
+/// use dioxus::prelude::*;
+/// use accordion_rs::dioxus::Accordion;
+///
+/// fn App() -> Element {
+///     rsx! {
+///         Accordion {
+///             default_expanded: true,
+///             expanded: rsx! { p { "This is the expanded content" } },
+///             collapsed: rsx! { p { "This is the collapsed content" } },
+///         }
+///     }
+/// }
+/// ```
+///
 /// ## Accordion with Custom Styles
 /// ```rust
 /// use dioxus::prelude::*;
@@ -228,62 +454,1131 @@ pub struct AccordionProps {
 ///     }
 /// }
 /// ```
+///
+/// ## Cancelable Close
+/// ```rust
+/// use dioxus::prelude::*;
+/// use accordion_rs::dioxus::Accordion;
+/// use accordion_rs::ToggleEvent;
+///
+/// fn App() -> Element {
+///     let has_unsaved_edits = use_signal(|| true);
+///
+///     rsx! {
+///         Accordion {
+///             default_expanded: true,
+///             expanded: rsx! { p { "Editing" } },
+///             collapsed: rsx! { p { "Edit" } },
+///             will_close: move |event: ToggleEvent| {
+///                 if has_unsaved_edits() {
+///                     event.prevent_default();
+///                 }
+///             },
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Accordion with Async Content
+/// ```rust
+/// use dioxus::prelude::*;
+/// use accordion_rs::dioxus::{Accordion, PanelLoader};
+///
+/// fn App() -> Element {
+///     let loader = use_hook(|| {
+///         PanelLoader::new(|| async { Ok(rsx! { p { "Loaded on first open" } }) })
+///     });
+///
+///     rsx! {
+///         Accordion {
+///             expanded: rsx! { p { "Details" } },
+///             collapsed: rsx! { p { "Details" } },
+///             loader: loader,
+///             loading: rsx! { p { "Loading..." } },
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Deep-Linked Accordion
+/// ```rust
+/// use dioxus::prelude::*;
+/// use accordion_rs::dioxus::Accordion;
+/// use accordion_rs::UrlUpdate;
+///
+/// fn App() -> Element {
+///     // Opened by `/faq#faq-shipping` or `/faq?open=faq-shipping`.
+///     rsx! {
+///         Accordion {
+///             aria_controls: "faq-shipping",
+///             deep_link: true,
+///             update_url: UrlUpdate::Hash,
+///             expanded: rsx! { p { "Shipping takes two days." } },
+///             collapsed: rsx! { p { "How long does shipping take?" } },
+///         }
+///     }
+/// }
+/// ```
+///
+/// ## Nested Accordions
+/// ```rust
+/// use dioxus::prelude::*;
+/// use accordion_rs::dioxus::{Accordion, Item, List};
+/// use accordion_rs::DescendantPolicy;
+///
+/// fn App() -> Element {
+///     rsx! {
+///         Accordion {
+///             expanded: rsx! { p { "Products" } },
+///             collapsed: rsx! { p { "Products" } },
+///             descendants: DescendantPolicy::Reset,
+///             List {
+///                 Item {
+///                     // Rendered as an `h4`, with the `accordion-depth-1` class.
+///                     Accordion {
+///                         expanded: rsx! { p { "Hardware" } },
+///                         collapsed: rsx! { p { "Hardware" } },
+///                         p { "Keyboards, mice and monitors." }
+///                     }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
 #[component]
-pub fn Accordion(mut props: AccordionProps) -> Element {
-    let toggle_expansion = {
-        move |_| {
-            if (props.expand)() {
-                props.will_close.call(());
-                props.expand.set(false);
-                props.did_close.call(());
+pub fn Accordion(props: AccordionProps) -> Element {
+    let parent = try_use_context::<AccordionParent>();
+    let config = use_config();
+    let duration = config.duration(props.duration);
+    let aria_enabled = config.aria_enabled(props.aria_enabled);
+    let depth = parent.as_ref().map_or(0, |parent| parent.depth + 1);
+    let group = try_use_context::<AccordionGroupContext>().filter(|group| group.depth == depth);
+    let group_key = use_hook(|| group.map(|mut group| group.register()));
+    let default_expanded = props.default_expanded;
+    use_effect(move || {
+        if let (Some(mut group), Some(key), true) = (group, group_key, default_expanded) {
+            group.set_open(key, true);
+        }
+    });
+    let parent_nested = parent.as_ref().map(|parent| parent.nested);
+    let nested_index = use_hook(|| parent_nested.map(|mut nested| nested.write().register()));
+    let own_state = use_signal(|| match (parent_nested, nested_index) {
+        (Some(nested), Some(index)) => nested
+            .read()
+            .remembered(index)
+            .unwrap_or(props.default_expanded),
+        _ => props.default_expanded,
+    });
+    let generated_id = use_hook(|| {
+        AccordionIds::generate(
+            parent
+                .as_ref()
+                .map(|parent| parent.id.as_str())
+                .zip(nested_index),
+        )
+    });
+    let ids = AccordionIds::new(&props.aria_controls, &generated_id);
+    if let (Some(mut group), Some(key)) = (group, group_key) {
+        group.members.write().set_id(key, &ids.base);
+    }
+    use_drop(move || {
+        if let (Some(mut group), Some(key)) = (group, group_key) {
+            group.unregister(key);
+        }
+    });
+    let heading_level = config.heading_level(
+        props.heading_level,
+        parent.as_ref().map(AccordionParent::heading_level),
+    );
+    let themed = props
+        .theme
+        .clone()
+        .or_else(|| parent.as_ref().and_then(AccordionParent::theme))
+        .or_else(|| config.theme.clone());
+    let theme = themed.clone().unwrap_or_default();
+    let mut context = use_context_provider(|| AccordionParent {
+        depth,
+        id: ids.base.clone(),
+        heading_level: Signal::new(heading_level),
+        theme: Signal::new(themed.clone()),
+        resets: Signal::new(0),
+        nested: CopyValue::new(NestedState::default()),
+    });
+    let mut inherited_level = context.heading_level;
+    let mut inherited_theme = context.theme;
+    use_effect(use_reactive!(|heading_level, themed| {
+        if *inherited_level.peek() != heading_level {
+            inherited_level.set(heading_level);
+        }
+        if *inherited_theme.peek() != themed {
+            inherited_theme.set(themed);
+        }
+    }));
+    let mut expand = props
+        .handle
+        .map(|handle| handle.expanded)
+        .or(props.expand)
+        .unwrap_or(own_state);
+
+    let is_expanded = match (group, group_key) {
+        (Some(group), Some(key)) => group.is_open(key),
+        _ => expand(),
+    };
+
+    let mut set_expanded = move |value: bool| match (group, group_key) {
+        (Some(mut group), Some(key)) => group.set_open(key, value),
+        _ => expand.set(value),
+    };
+
+    use_effect(move || {
+        let expanded = own_state();
+        if let (Some(mut nested), Some(index)) = (parent_nested, nested_index) {
+            nested.write().remember(index, expanded);
+        }
+    });
+
+    let parent_resets = parent.as_ref().map(|parent| parent.resets);
+    let mut seen_resets =
+        use_hook(|| CopyValue::new(parent_resets.map_or(0, |resets| *resets.peek())));
+    let mut own_resets = context.resets;
+    use_effect(move || {
+        let Some(resets) = parent_resets else {
+            return;
+        };
+        let resets = resets();
+        if seen_resets() != resets {
+            seen_resets.set(resets);
+            set_expanded(default_expanded);
+            *own_resets.write() += 1;
+        }
+    });
+
+    let persist_key = props.persist_key;
+    let storage = props.persist_storage;
+    let mut restored = use_hook(|| CopyValue::new(false));
+    use_effect(use_reactive!(|is_expanded| {
+        let Some(key) = persist_key.clone() else {
+            return;
+        };
+        if restored() {
+            save_persisted(storage, &key, is_expanded.to_string());
+            return;
+        }
+        restored.set(true);
+        spawn(async move {
+            match load_persisted(storage, &key).await {
+                Some(saved) => {
+                    if let Ok(saved) = saved.parse::<bool>() {
+                        if saved != is_expanded {
+                            set_expanded(saved);
+                        }
+                    }
+                }
+                None => save_persisted(storage, &key, is_expanded.to_string()),
+            }
+        });
+    }));
+
+    let mut phase = use_signal(|| Phase::at_rest(is_expanded));
+    let mut fallback = use_signal(|| None::<Task>);
+    let phase_value = if phase.read().is_expanded() == is_expanded {
+        phase()
+    } else {
+        Phase::at_rest(is_expanded)
+    };
+
+    let mut opened_before = use_hook(|| CopyValue::new(false));
+    if phase_value != Phase::Closed {
+        opened_before.set(true);
+    }
+    let is_mounted = props.mount.is_mounted(phase_value, opened_before());
+
+    let mut load_requested = use_signal(|| false);
+    use_effect(use_reactive!(|phase_value| {
+        if phase_value != Phase::Closed && !*load_requested.peek() {
+            load_requested.set(true);
+        }
+    }));
+    let loader = props.loader.clone();
+    let mut loaded = use_resource(move || {
+        let requested = load_requested();
+        let loader = loader.clone();
+        async move {
+            match (requested, loader) {
+                (true, Some(loader)) => Some(loader.load().await),
+                _ => None,
+            }
+        }
+    });
+    let retry_load = use_callback(move |_| loaded.restart());
+    let loaded_content = match &*loaded.read() {
+        _ if props.loader.is_none() => None,
+        Some(Some(Ok(content))) => Some(content.clone()),
+        Some(Some(Err(message))) => {
+            let error = PanelError {
+                message: message.clone(),
+                retry: retry_load,
+            };
+            Some(match props.error {
+                Some(error_view) => error_view.call(error),
+                None => default_error_view(error),
+            })
+        }
+        _ if opened_before() => Some(props.loading.clone()),
+        _ => None,
+    };
+    let is_hidden = phase_value == Phase::Closed;
+
+    if !is_mounted {
+        context.nested.write().restart();
+    }
+    let mut was_closed = use_hook(|| CopyValue::new(is_hidden));
+    let mut nested = context.nested;
+    let descendants = props.descendants;
+    use_effect(use_reactive!(|is_hidden| {
+        if is_hidden && !was_closed() && descendants == DescendantPolicy::Reset {
+            nested.write().forget();
+            *own_resets.write() += 1;
+        }
+        was_closed.set(is_hidden);
+    }));
+
+    let mut panel = use_signal(|| None::<Rc<MountedData>>);
+    let mut measured_height = use_signal(|| None::<f64>);
+    use_effect(use_reactive!(|phase_value| {
+        let height = measured_height();
+        let Some(panel) = panel() else {
+            return;
+        };
+        match (phase_value, height) {
+            (Phase::Opening | Phase::Closing, None) => {
+                spawn(async move {
+                    if let Ok(size) = panel.get_scroll_size().await {
+                        measured_height.set(Some(size.height));
+                    }
+                });
+            }
+            (Phase::Closing, Some(height)) if height > 0.0 => {
+                // Reading the layout commits the measured height before collapsing to zero.
+                spawn(async move {
+                    let _ = panel.get_scroll_size().await;
+                    measured_height.set(Some(0.0));
+                });
+            }
+            _ => {}
+        }
+    }));
+
+    let mut finish_transition = move |settled: Phase| {
+        phase.set(settled);
+        props.on_phase_change.call(settled);
+        if settled == Phase::Open {
+            props.did_open.call(());
+        } else {
+            props.did_close.call(());
+        }
+    };
+
+    let link_id = ids.base.clone();
+    let mut toggle_expansion = move || {
+        if props.disabled {
+            return;
+        }
+
+        let event = ToggleEvent::new(!is_expanded);
+        if is_expanded {
+            props.will_close.call(event.clone());
+        } else {
+            props.will_open.call(event.clone());
+        }
+        if event.default_prevented() {
+            return;
+        }
+
+        set_expanded(!is_expanded);
+        update_location(props.update_url, link_id.clone(), !is_expanded);
+        let next = if is_expanded {
+            Phase::Closing
+        } else {
+            Phase::Opening
+        };
+        phase.set(next);
+        measured_height.set(None);
+        props.on_phase_change.call(next);
+
+        if let Some(task) = fallback.take() {
+            task.cancel();
+        }
+        let mut timer = document::eval(&format!(
+            "setTimeout(() => dioxus.send(true), {});",
+            duration
+        ));
+        fallback.set(Some(spawn(async move {
+            if timer.recv::<bool>().await.is_ok() {
+                fallback.set(None);
+                finish_transition(next.settled());
+            }
+        })));
+    };
+
+    let request_toggle = use_callback({
+        let mut toggle_expansion = toggle_expansion.clone();
+        move |action: ControlAction| {
+            if action.applies(is_expanded) {
+                toggle_expansion();
+            }
+        }
+    });
+    let handle = props.handle;
+    let mut seen_request =
+        use_hook(|| CopyValue::new(handle.map_or(0, |handle| handle.request.peek().seq)));
+    use_effect(move || {
+        let Some(handle) = handle else {
+            return;
+        };
+        let request = (handle.request)();
+        if seen_request() != request.seq {
+            seen_request.set(request.seq);
+            request_toggle.call(request.action);
+        }
+    });
+    use_effect(use_reactive!(|phase_value| {
+        if let Some(handle) = handle {
+            let mut phase = handle.phase;
+            phase.set(phase_value);
+        }
+    }));
+
+    let deep_link = props.deep_link;
+    let linked = ids.clone();
+    use_effect(move || {
+        if !deep_link {
+            return;
+        }
+        let linked = linked.clone();
+        let mut script = document::eval(
+            r#"
+            const id = await dioxus.recv();
+            const send = () => dioxus.send([location.search, location.hash]);
+            (window.accordionDeepLinks ??= new Map()).set(id, send);
+            window.addEventListener("hashchange", send);
+            send();
+            "#,
+        );
+        let _ = script.send(&linked.header);
+        spawn(async move {
+            while let Ok((search, hash)) = script.recv::<(String, String)>().await {
+                if linked.is_linked(&linked_ids(&search, &hash)) {
+                    request_toggle.call(ControlAction::Open);
+                    scroll_into_view(&linked.header);
+                }
+            }
+        });
+    });
+
+    let linked_header = ids.header.clone();
+    use_drop(move || {
+        if deep_link {
+            remove_deep_link(&linked_header);
+        }
+    });
+
+    let on_header_keydown = move |e: KeyboardEvent| {
+        let Some(key) = HeaderKey::from_key(&e.key().to_string()) else {
+            return;
+        };
+        e.prevent_default();
+        focus_sibling_header(key);
+    };
+
+    let panel_id = ids.panel.clone();
+    let on_transition_end = move |e: TransitionEvent| {
+        // Events are delegated from the root, so compare the target with the
+        // panel itself rather than `current_target`.
+        if let Some(event) = e.data().downcast::<web_sys::TransitionEvent>() {
+            let on_panel = event
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                .is_some_and(|target| target.id() == panel_id);
+            if event.property_name() != "height" || !on_panel {
+                return;
+            }
+        }
+        e.stop_propagation();
+        if phase_value.is_transitioning() {
+            if let Some(task) = fallback.take() {
+                task.cancel();
+            }
+            finish_transition(phase_value.settled());
+        }
+    };
+
+    let indicator = (!props.hide_indicator).then(|| {
+        rsx! {
+            span {
+                class: INDICATOR_CLASS,
+                role: aria_enabled.then_some("img"),
+                aria_label: aria_enabled.then(|| indicator_label(&props.indicator_label, is_expanded).to_string()),
+                "data-placement": props.indicator_placement.as_str(),
+                "data-expanded": is_expanded.then_some(""),
+                style: indicator_style(props.indicator_placement, is_expanded),
+                {props.indicator.clone().unwrap_or_else(chevron)}
+            }
+        }
+    });
+    let (leading_indicator, trailing_indicator) = match props.indicator_placement {
+        Placement::Leading => (indicator, None),
+        Placement::Trailing => (None, indicator),
+    };
+
+    let header = rsx! {
+        button {
+            r#type: "button",
+            id: "{ids.header}",
+            class: {format!(
+                "{HEADER_CLASS} {} {} {}",
+                theme.header_class,
+                if is_expanded {
+                    Theme::pick(&props.expanded_class, &theme.expanded_class)
+                } else {
+                    Theme::pick(&props.collapsed_class, &theme.collapsed_class)
+                },
+                if props.disabled { props.disabled_class.as_str() } else { "" }
+            )},
+            style: {format!(
+                "{HEADER_STYLE} cursor: {}; {} {} {}",
+                if props.disabled { "not-allowed" } else { "pointer" },
+                theme.header_style,
+                if is_expanded {
+                    Theme::pick(&props.expanded_style, &theme.expanded_style)
+                } else {
+                    Theme::pick(&props.collapsed_style, &theme.collapsed_style)
+                },
+                if props.disabled { props.disabled_style.as_str() } else { "" }
+            )},
+            aria_expanded: if aria_enabled { Some(is_expanded.to_string()) } else { None },
+            aria_controls: if aria_enabled { Some(ids.panel.clone()) } else { None },
+            aria_disabled: (aria_enabled && props.disabled).then_some("true"),
+            "data-accordion-header": "",
+            "data-disabled": props.disabled.then_some("true"),
+            onclick: move |_| toggle_expansion(),
+            onkeydown: on_header_keydown,
+            {leading_indicator}
+            if is_expanded {
+                {props.expanded}
             } else {
-                props.will_open.call(());
-                props.expand.set(true);
-                props.did_open.call(());
+                {props.collapsed}
             }
+            {trailing_indicator}
         }
     };
+    let heading = match heading_level {
+        1 => rsx! { h1 { class: HEADING_CLASS, style: HEADING_STYLE, {header} } },
+        2 => rsx! { h2 { class: HEADING_CLASS, style: HEADING_STYLE, {header} } },
+        3 => rsx! { h3 { class: HEADING_CLASS, style: HEADING_STYLE, {header} } },
+        4 => rsx! { h4 { class: HEADING_CLASS, style: HEADING_STYLE, {header} } },
+        5 => rsx! { h5 { class: HEADING_CLASS, style: HEADING_STYLE, {header} } },
+        _ => rsx! { h6 { class: HEADING_CLASS, style: HEADING_STYLE, {header} } },
+    };
+
+    let size_rules = config.size_rules(props.size.as_ref(), &ids.base);
+    let size_id = size_rules.is_some().then(|| ids.base.clone());
 
     rsx! {
         div {
-            class: "{props.class}",
-            style: "{props.size.to_style()} {props.style}",
-            div {
-                class: {if (props.expand)() {
-                    props.expanded_class
-                } else {
-                    props.collapsed_class
-                }},
-                style: {format!(
-                    "cursor: pointer; transition: all {}ms; {}",
-                    props.duration,
-                    if (props.expand)() {
-                        props.expanded_style
-                    } else {
-                        props.collapsed_style
-                    }
-                )},
-                aria_expanded: if props.aria_enabled { Some((props.expand)().to_string()) } else { None },
-                aria_controls: if props.aria_enabled { Some(props.aria_controls) } else { None },
-                onclick: toggle_expansion,
-                if (props.expand)() {
-                    {props.expanded}
-                } else {
-                    {props.collapsed}
-                }
-            },
-            if (props.expand)() {
+            class: format!(
+                "{ROOT_CLASS} {} {}",
+                Theme::pick(&props.class, &theme.class),
+                props.indent_class.clone().unwrap_or_else(|| depth_class(depth))
+            ),
+            style: "{ROOT_STYLE} {config.properties(props.duration, props.size.as_ref(), depth > 0)}{Theme::pick(&props.style, &theme.style)}",
+            "data-phase": phase_value.as_str(),
+            "data-accordion-id": size_id,
+            if let Some(rules) = size_rules {
+                style { {rules} }
+            }
+            {heading},
+            if is_mounted {
                 div {
-                    id: "{props.aria_controls}",
-                    class: "{props.content_class}",
-                    style: "overflow: hidden; transition: all {props.duration}ms; {props.content_style}",
+                    id: "{ids.panel}",
+                    role: if aria_enabled { Some("region") } else { None },
+                    aria_labelledby: if aria_enabled { Some(ids.header.clone()) } else { None },
+                    class: "{PANEL_CLASS} {Theme::pick(&props.content_class, &theme.content_class)}",
+                    hidden: is_hidden,
+                    "inert": is_hidden.then_some("true"),
+                    style: "{PANEL_STYLE} {panel_height(phase_value, measured_height())} {Theme::pick(&props.content_style, &theme.content_style)}",
+                    onmounted: move |e| panel.set(Some(e.data())),
+                    ontransitionend: on_transition_end,
                     {props.children}
+                    {loaded_content}
                 }
             }
         }
     }
 }
 
+/// Controller handle of an `Accordion`, created by `use_accordion`.
+///
+/// Passed to an accordion's `handle` prop, it lets code outside the accordion open and close it
+/// the way a header click does: `will_open`/`will_close` can cancel the change, the panel animates,
+/// and `did_open`/`did_close` fire once it settles.
+#[derive(Clone, Copy, PartialEq)]
+pub struct AccordionHandle {
+    expanded: Signal<bool>,
+    phase: Signal<Phase>,
+    request: Signal<ControlRequest>,
+}
+
+impl AccordionHandle {
+    /// Opens the accordion, unless it is already open.
+    pub fn open(&self) {
+        self.request(ControlAction::Open);
+    }
+
+    /// Closes the accordion, unless it is already closed.
+    pub fn close(&self) {
+        self.request(ControlAction::Close);
+    }
+
+    /// Opens a closed accordion, or closes an open one.
+    pub fn toggle(&self) {
+        self.request(ControlAction::Toggle);
+    }
+
+    /// Whether the accordion is expanded.
+    pub fn is_open(&self) -> bool {
+        (self.expanded)()
+    }
+
+    /// Current lifecycle phase of the accordion.
+    pub fn phase(&self) -> Phase {
+        (self.phase)()
+    }
+
+    fn request(&self, action: ControlAction) {
+        let mut request = self.request;
+        let next = request.peek().next(action);
+        request.set(next);
+    }
+}
+
+/// Creates a controller handle for an `Accordion`, starting expanded if `default_expanded` is set.
+///
+/// # Examples
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use accordion_rs::dioxus::{use_accordion, Accordion};
+///
+/// fn App() -> Element {
+///     let handle = use_accordion(false);
+///
+///     rsx! {
+///         a { href: "#details", onclick: move |_| handle.open(), "Show details" }
+///         Accordion {
+///             handle,
+///             expanded: rsx! { p { "Details (open)" } },
+///             collapsed: rsx! { p { "Details" } },
+///         }
+///     }
+/// }
+/// ```
+pub fn use_accordion(default_expanded: bool) -> AccordionHandle {
+    AccordionHandle {
+        expanded: use_signal(|| default_expanded),
+        phase: use_signal(|| Phase::at_rest(default_expanded)),
+        request: use_signal(ControlRequest::default),
+    }
+}
+
+/// Moves focus from the focused accordion header to one of its siblings.
+///
+/// The headers are looked up in the DOM, and the target index is computed by `HeaderKey`.
+fn focus_sibling_header(key: HeaderKey) {
+    let mut script = document::eval(&format!(
+        r#"
+        const header = document.activeElement;
+        const parent = header?.hasAttribute("data-accordion-header") && header.parentElement?.parentElement?.parentElement;
+        const headers = parent ? [...parent.querySelectorAll("{SIBLING_HEADERS}")] : [];
+        dioxus.send([headers.indexOf(header), headers.length]);
+        const target = await dioxus.recv();
+        headers[target]?.focus();
+        "#
+    ));
+    spawn(async move {
+        if let Ok((current, count)) = script.recv::<(usize, usize)>().await {
+            if let Some(target) = key.focus_index(current, count) {
+                let _ = script.send(target);
+            }
+        }
+    });
+}
+
+/// Reads `key` from the web storage, resolving to `None` when it is missing or storage is unavailable.
+async fn load_persisted(storage: PersistStorage, key: &str) -> Option<String> {
+    let mut script = document::eval(
+        r#"
+        const [storage, key] = await dioxus.recv();
+        try {
+            dioxus.send(window[storage].getItem(key));
+        } catch {
+            dioxus.send(null);
+        }
+        "#,
+    );
+    script.send((storage.name(), key)).ok()?;
+    script.recv::<Option<String>>().await.ok().flatten()
+}
+
+/// Writes `value` under `key` to the web storage, ignoring unavailable storage.
+fn save_persisted(storage: PersistStorage, key: &str, value: String) {
+    let script = document::eval(
+        r#"
+        const [storage, key, value] = await dioxus.recv();
+        try {
+            window[storage].setItem(key, value);
+        } catch {}
+        "#,
+    );
+    let _ = script.send((storage.name(), key, value));
+}
+
+/// Scrolls the element with the given id into view, if it exists.
+fn scroll_into_view(id: &str) {
+    let script = document::eval(
+        r#"
+        const id = await dioxus.recv();
+        document.getElementById(id)?.scrollIntoView();
+        "#,
+    );
+    let _ = script.send(id);
+}
+
+/// Removes the `hashchange` listener registered for the accordion `id` by its deep link.
+fn remove_deep_link(id: &str) {
+    let script = document::eval(
+        r#"
+        const id = await dioxus.recv();
+        const send = window.accordionDeepLinks?.get(id);
+        if (send) {
+            window.removeEventListener("hashchange", send);
+            window.accordionDeepLinks.delete(id);
+        }
+        "#,
+    );
+    let _ = script.send(id);
+}
+
+/// Rewrites the browser's URL after the accordion `id` opened or closed, keeping the history state.
+fn update_location(update: UrlUpdate, id: String, open: bool) {
+    if update == UrlUpdate::None {
+        return;
+    }
+    let mut script = document::eval(
+        r#"
+        dioxus.send([location.pathname, location.search, location.hash]);
+        const url = await dioxus.recv();
+        if (url !== null) {
+            history.replaceState(history.state, "", url);
+        }
+        "#,
+    );
+    spawn(async move {
+        if let Ok((path, search, hash)) = script.recv::<(String, String, String)>().await {
+            let _ = script.send(update.apply(&path, &search, &hash, &id, open));
+        }
+    });
+}
+
+/// Future returned by a `PanelLoader`.
+pub type PanelFuture = Pin<Box<dyn Future<Output = Result<Element, String>>>>;
+
+/// Async loader for the content of an accordion's panel.
+///
+/// The loader runs the first time the accordion opens. Its output is kept, so closing and
+/// reopening the accordion doesn't load the content again.
+#[derive(Clone)]
+pub struct PanelLoader(Rc<dyn Fn() -> PanelFuture>);
+
+impl PanelLoader {
+    /// Creates a loader from an async function producing the panel content or an error message.
+    pub fn new<F, Fut>(load: F) -> Self
+    where
+        F: Fn() -> Fut + 'static,
+        Fut: Future<Output = Result<Element, String>> + 'static,
+    {
+        Self(Rc::new(move || Box::pin(load())))
+    }
+
+    fn load(&self) -> PanelFuture {
+        (self.0)()
+    }
+}
+
+impl PartialEq for PanelLoader {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A failed `PanelLoader` run, passed to the accordion's `error` view.
+#[derive(Clone, PartialEq)]
+pub struct PanelError {
+    /// The error message returned by the loader.
+    pub message: String,
+
+    /// Runs the loader again.
+    pub retry: Callback<()>,
+}
+
+/// Built-in indicator, a chevron pointing down that turns up while expanded.
+fn chevron() -> Element {
+    rsx! {
+        svg {
+            view_box: "0 0 24 24",
+            width: "1em",
+            height: "1em",
+            fill: "none",
+            stroke: "currentColor",
+            stroke_width: "2",
+            stroke_linecap: "round",
+            stroke_linejoin: "round",
+            "aria-hidden": "true",
+            path { d: "M6 9l6 6 6-6" }
+        }
+    }
+}
+
+fn default_error_view(error: PanelError) -> Element {
+    rsx! {
+        div {
+            role: "alert",
+            "{error.message}"
+            button {
+                r#type: "button",
+                onclick: move |_| error.retry.call(()),
+                "Retry"
+            }
+        }
+    }
+}
+
+/// Context provided by an accordion to the content of its panel.
+///
+/// Nested accordions read it to find their depth, default their heading level, indentation
+/// class and ids, and remember or reset their state when the parent closes.
+#[derive(Clone)]
+pub struct AccordionParent {
+    depth: usize,
+    id: String,
+    heading_level: Signal<u8>,
+    theme: Signal<Option<Theme>>,
+    resets: Signal<usize>,
+    nested: CopyValue<NestedState>,
+}
+
+impl AccordionParent {
+    /// Nesting depth of the parent accordion: `0` for a top-level accordion.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Base id of the parent accordion, prefixing the ids generated for nested accordions.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Heading level of the parent accordion's header.
+    pub fn heading_level(&self) -> u8 {
+        (self.heading_level)()
+    }
+
+    /// Theme of the parent accordion, inherited by the lists, items and accordions inside it.
+    pub fn theme(&self) -> Option<Theme> {
+        self.theme.cloned()
+    }
+}
+
+/// Context shared by an `AccordionGroup` with the accordions rendered inside it.
+///
+/// `expand_all`, `collapse_all` and `toggle` change the group's state directly: they skip the
+/// accordions' `will_open`/`will_close` and `did_open`/`did_close` callbacks, and the panels
+/// switch without animating. So does an accordion that `single` mode closes when another opens.
+#[derive(Clone, Copy)]
+pub struct AccordionGroupContext {
+    state: Signal<GroupState>,
+    depth: usize,
+    single: CopyValue<bool>,
+    members: CopyValue<GroupMembers>,
+}
+
+impl AccordionGroupContext {
+    /// Hands out the key of the next accordion joining the group.
+    fn register(&mut self) -> usize {
+        self.members.write().register()
+    }
+
+    /// Removes the accordion registered under `key`, closing it so it no longer counts as open.
+    ///
+    /// Does nothing once the group itself is dropped.
+    fn unregister(&mut self, key: usize) {
+        if let Ok(mut members) = self.members.try_write() {
+            members.unregister(key);
+        }
+        if let Ok(mut state) = self.state.try_write() {
+            state.close(key);
+        }
+    }
+
+    /// Whether the accordion registered under `key` is open.
+    pub fn is_open(&self, key: usize) -> bool {
+        self.state.read().is_open(key)
+    }
+
+    /// Opens or closes the accordion registered under `key`, honoring `single` mode.
+    pub fn set_open(&mut self, key: usize, open: bool) {
+        let single = (self.single)();
+        self.state.write().set_open(key, open, single);
+    }
+
+    /// Opens every accordion of the group, or only the first one in `single` mode.
+    pub fn expand_all(&mut self) {
+        self.set_all(true);
+    }
+
+    /// Closes every accordion of the group.
+    pub fn collapse_all(&mut self) {
+        self.set_all(false);
+    }
+
+    fn set_all(&mut self, open: bool) {
+        let keys = self.members.read().keys();
+        let single = (self.single)();
+        self.state.write().set_all(&keys, open, single);
+    }
+
+    /// Opens or closes the accordion whose id, `aria_controls` or the generated one, is `id`.
+    ///
+    /// Ids that don't belong to the group are ignored.
+    pub fn toggle(&mut self, id: &str) {
+        let key = self.members.read().key(id);
+        if let Some(key) = key {
+            let open = self.state.peek().is_open(key);
+            self.set_open(key, !open);
+        }
+    }
+
+    /// Whether all, none or some of the group's accordions are open.
+    pub fn status(&self) -> GroupStatus {
+        self.state.read().status(&self.members.read().keys())
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct AccordionGroupProps {
+    /// The accordions coordinated by the group.
+    ///
+    /// Accordions rendered below the group join it, except those nested in the panel of another accordion.
+    pub children: Element,
+
+    /// Whether at most one accordion can be open at a time.
+    ///
+    /// If `true`, opening an accordion closes every other accordion in the group.
+    /// Defaults to `false`.
+    #[props(default)]
+    pub single: bool,
+
+    /// Web storage key under which the group's open accordions are saved and restored.
+    ///
+    /// Accordions are identified by their order in the group. Defaults to `None`.
+    #[props(default, into)]
+    pub persist_key: Option<String>,
+
+    /// Web storage used with `persist_key`.
+    ///
+    /// Defaults to `PersistStorage::Local`.
+    #[props(default)]
+    pub persist_storage: PersistStorage,
+
+    /// The inline style for the group container.
+    ///
+    /// Defaults to an empty string.
+    #[props(default, into)]
+    pub style: String,
+
+    /// The CSS class for the group container.
+    ///
+    /// Defaults to an empty string.
+    #[props(default, into)]
+    pub class: String,
+}
+
+/// AccordionGroup Component
+///
+/// A Dioxus component that owns the expansion state of every `Accordion` rendered inside it,
+/// so the accordions don't need an `expand` signal each. With `single` set, opening one
+/// accordion closes the others.
+///
+/// # Examples
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use accordion_rs::dioxus::{Accordion, AccordionGroup};
+///
+/// fn App() -> Element {
+///     rsx! {
+///         AccordionGroup {
+///             single: true,
+///             Accordion {
+///                 expanded: rsx! { p { "First (open)" } },
+///                 collapsed: rsx! { p { "First" } },
+///             }
+///             Accordion {
+///                 expanded: rsx! { p { "Second (open)" } },
+///                 collapsed: rsx! { p { "Second" } },
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn AccordionGroup(props: AccordionGroupProps) -> Element {
+    let depth = try_use_context::<AccordionParent>().map_or(0, |parent| parent.depth + 1);
+    let mut context = use_context_provider(|| AccordionGroupContext {
+        state: Signal::new(GroupState::default()),
+        depth,
+        single: CopyValue::new(props.single),
+        members: CopyValue::new(GroupMembers::default()),
+    });
+    context.single.set(props.single);
+
+    let persist_key = props.persist_key;
+    let storage = props.persist_storage;
+    let mut restored = use_hook(|| CopyValue::new(false));
+    let mut state = context.state;
+    let members = context.members;
+    use_effect(move || {
+        let keys = members.read().keys();
+        let encoded = state.read().encode(&keys);
+        let Some(key) = persist_key.clone() else {
+            return;
+        };
+        if restored() {
+            save_persisted(storage, &key, encoded);
+            return;
+        }
+        restored.set(true);
+        spawn(async move {
+            match load_persisted(storage, &key).await {
+                Some(saved) => state.set(GroupState::decode(&saved, &members.read().keys())),
+                None => save_persisted(storage, &key, encoded),
+            }
+        });
+    });
+
+    rsx! {
+        div {
+            class: "{GROUP_CLASS} {props.class}",
+            style: "{props.style}",
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct AccordionConfigProviderProps {
+    /// The content whose accordions use the provided defaults.
+    pub children: Element,
+
+    /// Default theme of accordions that don't set one or inherit one from a parent accordion.
+    ///
+    /// Defaults to the surrounding provider's theme, if any.
+    #[props(default)]
+    pub theme: Option<Theme>,
+
+    /// Default animation duration in milliseconds.
+    ///
+    /// Defaults to the surrounding provider's duration, or else `600` milliseconds.
+    #[props(default)]
+    pub duration: Option<u64>,
+
+    /// Default size of the accordions.
+    ///
+    /// Defaults to the surrounding provider's size, or else `Size::XXLarge`.
+    #[props(default)]
+    pub size: Option<Size>,
+
+    /// Whether accordions render ARIA attributes by default.
+    ///
+    /// Defaults to the surrounding provider's value, or else `true`.
+    #[props(default)]
+    pub aria_enabled: Option<bool>,
+
+    /// Default heading level of top-level accordions; nested ones step one level below their parent.
+    ///
+    /// Defaults to the surrounding provider's level, or else `3`.
+    #[props(default)]
+    pub heading_level: Option<u8>,
+}
+
+/// AccordionConfigProvider Component
+///
+/// A Dioxus component supplying default `theme`, `duration`, `size`, `aria_enabled` and
+/// `heading_level` values to every `Accordion` rendered inside it. An accordion's own props
+/// override the provided defaults, and nested providers override the fields they set.
+///
+/// # Examples
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use accordion_rs::dioxus::{Accordion, AccordionConfigProvider};
+/// use accordion_rs::{Size, Theme};
+///
+/// fn App() -> Element {
+///     rsx! {
+///         AccordionConfigProvider {
+///             theme: Theme::dark(),
+///             duration: 300,
+///             size: Size::Large,
+///             heading_level: 2,
+///             Accordion {
+///                 expanded: rsx! { p { "Fast (open)" } },
+///                 collapsed: rsx! { p { "Fast" } },
+///             }
+///             Accordion {
+///                 expanded: rsx! { p { "Slow (open)" } },
+///                 collapsed: rsx! { p { "Slow" } },
+///                 duration: 1000,
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn AccordionConfigProvider(props: AccordionConfigProviderProps) -> Element {
+    let outer = try_use_context::<Signal<AccordionConfig>>();
+    let config = AccordionConfig {
+        theme: props.theme,
+        duration: props.duration,
+        size: props.size.clone(),
+        aria_enabled: props.aria_enabled,
+        heading_level: props.heading_level,
+    }
+    .or(outer.map(|outer| outer()).as_ref());
+    let mut context = use_context_provider(|| Signal::new(config.clone()));
+    // Descendants read the signal, so syncing it re-renders them when the props change.
+    use_effect(use_reactive!(|config| {
+        if *context.peek() != config {
+            context.set(config);
+        }
+    }));
+
+    rsx! {
+        {props.children}
+    }
+}
+
 #[derive(Props, PartialEq, Clone)]
 pub struct ItemProps {
     /// The child elements of the item.
@@ -294,14 +1589,14 @@ pub struct ItemProps {
     /// The inline style for the item container.
     ///
     /// Defaults to an empty string.
-    #[props(default = "")]
-    pub style: &'static str,
+    #[props(default, into)]
+    pub style: String,
 
     /// The CSS class for the item container.
     ///
     /// Defaults to an empty string.
-    #[props(default = "")]
-    pub class: &'static str,
+    #[props(default, into)]
+    pub class: String,
 
     /// The alignment of the item content.
     ///
@@ -314,29 +1609,62 @@ pub struct ItemProps {
     ///
     /// This text typically appears as a header or label for the item.
     /// Defaults to an empty string.
-    #[props(default = "")]
-    pub title: &'static str,
+    #[props(default, into)]
+    pub title: String,
 
     /// The icon associated with the item.
     ///
-    /// This can be a URL to an image or an icon class name.
-    /// Defaults to an empty string.
-    #[props(default = "")]
-    pub icon: &'static str,
+    /// Markup displayed alongside the title, such as an emoji, an inline SVG or an icon component.
+    /// Defaults to `None`.
+    #[props(default)]
+    pub icon: Option<Element>,
+
+    /// The side of the title on which the icon is displayed.
+    ///
+    /// Defaults to `Placement::Leading`.
+    #[props(default)]
+    pub icon_placement: Placement,
+
+    /// An additional class for the `<span>` wrapping the icon.
+    ///
+    /// The span always has the `accordion-item-icon` class, spaced from the title by the default
+    /// stylesheet. Defaults to an empty string.
+    #[props(default, into)]
+    pub icon_class: String,
+
+    /// The theme supplying the item's class and style when they are empty.
+    ///
+    /// Defaults to the theme of the surrounding accordion, if any.
+    #[props(default)]
+    pub theme: Option<Theme>,
 }
 
 #[component]
 pub fn Item(props: ItemProps) -> Element {
+    let theme = use_theme(props.theme);
+    let icon = props.icon.map(|icon| {
+        rsx! {
+            span {
+                class: "{ITEM_ICON_CLASS} {props.icon_class}",
+                "data-placement": props.icon_placement.as_str(),
+                {icon}
+            }
+        }
+    });
+    let (leading_icon, trailing_icon) = match props.icon_placement {
+        Placement::Leading => (icon, None),
+        Placement::Trailing => (None, icon),
+    };
+
     rsx! {
         li {
-            class: "{props.class}",
-            style: "{props.align.to_style()} {props.style}",
-            if !props.icon.is_empty() {
-                span { class: "mr-2", "{props.icon}" }
-            }
+            class: "{ITEM_CLASS} {Theme::pick(&props.class, &theme.item_class)}",
+            style: "{props.align.to_style()} {Theme::pick(&props.style, &theme.item_style)}",
+            {leading_icon}
             if !props.title.is_empty() {
                 strong { "{props.title}" }
             }
+            {trailing_icon}
             {props.children}
         }
     }
@@ -353,23 +1681,138 @@ pub struct ButtonProps {
     ///
     /// This allows for custom styling directly applied to the button element.
     /// Defaults to an empty string.
-    #[props(default = "")]
-    pub style: &'static str,
+    #[props(default, into)]
+    pub style: String,
 
     /// The CSS class for the button.
     ///
     /// This applies a custom CSS class to the button for styling.
     /// Defaults to an empty string.
-    #[props(default = "")]
-    pub class: &'static str,
+    #[props(default, into)]
+    pub class: String,
+
+    /// Handler invoked when the button is clicked.
+    ///
+    /// Defaults to no-op.
+    #[props(default)]
+    pub onclick: EventHandler<MouseEvent>,
 }
 
 #[component]
 pub fn Button(props: ButtonProps) -> Element {
     rsx! {
         button {
-            class: "{props.class}",
+            class: "{BUTTON_CLASS} {props.class}",
             style: "{props.style}",
+            onclick: move |e| props.onclick.call(e),
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct ExpandAllButtonProps {
+    /// The content of the button.
+    ///
+    /// Defaults to `"Expand all"`.
+    #[props(default = rsx! { "Expand all" })]
+    pub children: Element,
+
+    /// The inline style for the button.
+    ///
+    /// Defaults to an empty string.
+    #[props(default, into)]
+    pub style: String,
+
+    /// The CSS class for the button.
+    ///
+    /// Defaults to an empty string.
+    #[props(default, into)]
+    pub class: String,
+}
+
+/// A `Button` opening every accordion of the surrounding `AccordionGroup`.
+///
+/// Outside of an `AccordionGroup`, the button does nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use accordion_rs::dioxus::{Accordion, AccordionGroup, CollapseAllButton, ExpandAllButton};
+///
+/// fn App() -> Element {
+///     rsx! {
+///         AccordionGroup {
+///             ExpandAllButton {}
+///             CollapseAllButton { "Hide everything" }
+///             Accordion {
+///                 expanded: rsx! { p { "Installation (open)" } },
+///                 collapsed: rsx! { p { "Installation" } },
+///             }
+///             Accordion {
+///                 expanded: rsx! { p { "Configuration (open)" } },
+///                 collapsed: rsx! { p { "Configuration" } },
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn ExpandAllButton(props: ExpandAllButtonProps) -> Element {
+    let group = try_use_context::<AccordionGroupContext>();
+
+    rsx! {
+        Button {
+            class: props.class,
+            style: props.style,
+            onclick: move |_| {
+                if let Some(mut group) = group {
+                    group.expand_all();
+                }
+            },
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, PartialEq, Clone)]
+pub struct CollapseAllButtonProps {
+    /// The content of the button.
+    ///
+    /// Defaults to `"Collapse all"`.
+    #[props(default = rsx! { "Collapse all" })]
+    pub children: Element,
+
+    /// The inline style for the button.
+    ///
+    /// Defaults to an empty string.
+    #[props(default, into)]
+    pub style: String,
+
+    /// The CSS class for the button.
+    ///
+    /// Defaults to an empty string.
+    #[props(default, into)]
+    pub class: String,
+}
+
+/// A `Button` closing every accordion of the surrounding `AccordionGroup`.
+///
+/// Outside of an `AccordionGroup`, the button does nothing.
+#[component]
+pub fn CollapseAllButton(props: CollapseAllButtonProps) -> Element {
+    let group = try_use_context::<AccordionGroupContext>();
+
+    rsx! {
+        Button {
+            class: props.class,
+            style: props.style,
+            onclick: move |_| {
+                if let Some(mut group) = group {
+                    group.collapse_all();
+                }
+            },
             {props.children}
         }
     }
@@ -386,24 +1829,49 @@ pub struct ListProps {
     ///
     /// You can provide custom CSS styles directly to the list container using this property.
     /// Defaults to an empty string.
-    #[props(default = "")]
-    pub style: &'static str,
+    #[props(default, into)]
+    pub style: String,
 
     /// The CSS class for the list container.
     ///
     /// This class is used to apply custom styling to the list container via external CSS.
     /// Defaults to an empty string.
-    #[props(default = "")]
-    pub class: &'static str,
+    #[props(default, into)]
+    pub class: String,
+
+    /// The theme supplying the list's class and style when they are empty.
+    ///
+    /// Defaults to the theme of the surrounding accordion, if any.
+    #[props(default)]
+    pub theme: Option<Theme>,
 }
 
 #[component]
 pub fn List(props: ListProps) -> Element {
+    let theme = use_theme(props.theme);
+
     rsx! {
         ul {
-            class: "{props.class}",
-            style: "{props.style}",
+            class: "{LIST_CLASS} {Theme::pick(&props.class, &theme.list_class)}",
+            style: Theme::pick(&props.style, &theme.list_style),
             {props.children}
         }
     }
 }
+
+/// The given theme, or else the theme of the surrounding accordion or `AccordionConfigProvider`.
+fn use_theme(theme: Option<Theme>) -> Theme {
+    let parent = try_use_context::<AccordionParent>();
+    let config = use_config();
+    theme
+        .or_else(|| parent.and_then(|parent| parent.theme()))
+        .or(config.theme)
+        .unwrap_or_default()
+}
+
+/// The defaults of the surrounding `AccordionConfigProvider`, if any.
+fn use_config() -> AccordionConfig {
+    try_use_context::<Signal<AccordionConfig>>()
+        .map(|config| config())
+        .unwrap_or_default()
+}
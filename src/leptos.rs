@@ -1,5 +1,21 @@
-use crate::common::{Align, Size};
+use crate::common::{
+    depth_class, indicator_label as label_indicator, indicator_style, location_links, panel_height,
+    scroll_into_view, AccordionConfig, AccordionIds, Align, ControlAction, ControlRequest,
+    DescendantPolicy, GroupMembers, GroupState, GroupStatus, HeaderKey, MountPolicy, NestedState,
+    PersistStorage, Phase, Placement, Size, Theme, ToggleEvent, UrlUpdate, BUTTON_CLASS,
+    GROUP_CLASS, HEADER_CLASS, HEADER_STYLE, HEADING_CLASS, HEADING_STYLE, INDICATOR_CLASS,
+    ITEM_CLASS, ITEM_ICON_CLASS, LIST_CLASS, PANEL_CLASS, PANEL_STYLE, ROOT_CLASS, ROOT_STYLE,
+    SIBLING_HEADERS,
+};
+use leptos::context::Provider;
+use leptos::html::Div;
 use leptos::prelude::*;
+use leptos::wasm_bindgen::JsCast;
+use leptos::web_sys::{Element, HtmlElement};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Accordion Component
 ///
@@ -11,26 +27,49 @@ use leptos::prelude::*;
 ///
 /// # Properties
 ///
-/// - **expand**: A tuple signal containing a `ReadSignal<bool>` and a `WriteSignal<bool>` for tracking and updating the expansion state of the accordion.
+/// - **expand**: Reactive state tracking and updating the expansion state of the accordion, such as an `RwSignal<bool>` or a `(ReadSignal<bool>, WriteSignal<bool>)` tuple (`Option<ExpandState>`). Inside an `AccordionGroup`, the group owns the state instead. Default: `None`.
+/// - **handle**: A controller handle from `use_accordion`, driving the accordion through its callbacks (`Option<AccordionHandle>`). Default: `None`.
+/// - **default_expanded**: Whether an uncontrolled accordion starts expanded (`bool`). Default: `false`.
 /// - **expanded**: A view content to display when the accordion is expanded (`Box<dyn Fn() -> AnyView>`).
 /// - **collapsed**: A view content to display when the accordion is collapsed (`Box<dyn Fn() -> AnyView>`).
 /// - **children**: Child elements inside the accordion (`Children`).
-/// - **size**: Defines the size of the accordion (`Size`). Default: `Size::XXLarge`.
-/// - **aria_controls**: The ARIA controls attribute for accessibility (`&'static str`). Default: `""`.
-/// - **style**: Inline styles applied to the accordion container (`&'static str`). Default: `""`.
-/// - **expanded_style**: Inline styles applied when the accordion is expanded (`&'static str`). Default: `""`.
-/// - **collapsed_style**: Inline styles applied when the accordion is collapsed (`&'static str`). Default: `""`.
-/// - **content_style**: Inline styles applied to the accordion's content container (`&'static str`). Default: `""`.
-/// - **class**: CSS class for the accordion container (`&'static str`). Default: `""`.
-/// - **expanded_class**: CSS class applied when the accordion is expanded (`&'static str`). Default: `""`.
-/// - **collapsed_class**: CSS class applied when the accordion is collapsed (`&'static str`). Default: `""`.
-/// - **content_class**: CSS class applied to the content container (`&'static str`). Default: `""`.
-/// - **aria_enabled**: Whether ARIA attributes should be included for accessibility (`bool`). Default: `true`.
-/// - **duration**: Duration of the expand/collapse transition in milliseconds (`u64`). Default: `600`.
-/// - **will_open**: Callback triggered before the accordion starts expanding (`Callback<()>`). Default: no-op.
+/// - **size**: Defines the size of the accordion (`Option<Size>`). Default: the provided size, or `Size::XXLarge`.
+/// - **aria_controls**: The ARIA controls attribute and panel id (`String`). Default: `""`, generating a unique id.
+/// - **theme**: Shared classes and styles, overridden by the props below (`Option<Theme>`). Default: the parent accordion's or provided theme.
+/// - **style**: Inline styles applied to the accordion container (`String`). Default: `""`.
+/// - **expanded_style**: Inline styles applied when the accordion is expanded (`String`). Default: `""`.
+/// - **collapsed_style**: Inline styles applied when the accordion is collapsed (`String`). Default: `""`.
+/// - **disabled_style**: Inline styles applied to the header while disabled (`String`). Default: `""`.
+/// - **content_style**: Inline styles applied to the accordion's content container (`String`). Default: `""`.
+/// - **class**: CSS class for the accordion container (`String`). Default: `""`.
+/// - **expanded_class**: CSS class applied when the accordion is expanded (`String`). Default: `""`.
+/// - **collapsed_class**: CSS class applied when the accordion is collapsed (`String`). Default: `""`.
+/// - **disabled_class**: CSS class applied to the header while disabled (`String`). Default: `""`.
+/// - **content_class**: CSS class applied to the content container (`String`). Default: `""`.
+/// - **aria_enabled**: Whether ARIA attributes should be included for accessibility (`Option<bool>`). Default: the provided value, or `true`.
+/// - **heading_level**: Level of the heading wrapping the header button, from `1` to `6` (`Option<u8>`). Default: one below the parent accordion's, the provided level, or `3`.
+/// - **indent_class**: Indentation class for the accordion container (`Option<String>`). Default: `accordion-depth-N` for nesting depth `N`.
+/// - **descendants**: Whether nested accordions are remembered or reset when the panel closes (`DescendantPolicy`). Default: `DescendantPolicy::Remember`.
+/// - **duration**: Duration of the expand/collapse transition in milliseconds (`Option<u64>`). Default: the provided duration, or `600`.
+/// - **disabled**: Whether the header ignores clicks while keeping the current state (`bool`). Default: `false`.
+/// - **indicator**: Custom expand/collapse indicator, rotated with the state (`Option<ViewFn>`). Default: a chevron.
+/// - **indicator_placement**: Side of the header content on which the indicator is shown (`Placement`). Default: `Placement::Trailing`.
+/// - **hide_indicator**: Whether the header shows no indicator (`bool`). Default: `false`.
+/// - **indicator_label**: Accessible label of the indicator (`String`). Default: `""`, labelling it "Expand" or "Collapse".
+/// - **mount**: When the content container is present in the DOM (`MountPolicy`). Default: `MountPolicy::UnmountOnClose`.
+/// - **persist_key**: Web storage key saving and restoring the expanded state (`Option<String>`). Default: `None`.
+/// - **persist_storage**: Web storage used with `persist_key` (`PersistStorage`). Default: `PersistStorage::Local`.
+/// - **deep_link**: Whether the accordion opens when the URL's hash or `open` query parameter names its id (`bool`). Default: `false`.
+/// - **update_url**: How toggling the accordion rewrites the page URL (`UrlUpdate`). Default: `UrlUpdate::None`.
+/// - **loader**: Async loader for panel content, run on first expand (`Option<PanelLoader>`). Default: `None`.
+/// - **loading**: Placeholder shown while the loader runs (`ViewFn`). Default: empty.
+/// - **error**: View rendered when the loader fails (`Option<Callback<PanelError, AnyView>>`). Default: alert with a retry button.
+/// - **will_open**: Callback triggered before the accordion starts expanding; can cancel it (`Callback<ToggleEvent>`). Default: no-op.
 /// - **did_open**: Callback triggered after the accordion finishes expanding (`Callback<()>`). Default: no-op.
-/// - **will_close**: Callback triggered before the accordion starts collapsing (`Callback<()>`). Default: no-op.
+/// - **will_close**: Callback triggered before the accordion starts collapsing; can cancel it (`Callback<ToggleEvent>`). Default: no-op.
 /// - **did_close**: Callback triggered after the accordion finishes collapsing (`Callback<()>`). Default: no-op.
+/// - **on_phase_change**: Callback triggered when the lifecycle phase changes (`Callback<Phase>`). Default: no-op.
+/// - **on_toggle**: Callback receiving the new expansion state whenever the accordion changes it (`Option<Callback<bool>>`). Default: `None`.
 ///
 /// # Features
 /// - Customizable expanded and collapsed content.
@@ -65,6 +104,62 @@ use leptos::prelude::*;
 /// }
 /// ```
 ///
+/// ## Uncontrolled Accordion
+/// ```rust
+/// use leptos::prelude::*;
+/// use accordion_rs::leptos::{Accordion, List, Item};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <Accordion
+///             default_expanded=true
+///             expanded={Box::new(|| view! { <p>"Expanded content."</p> }.into_any())}
+///             collapsed={Box::new(|| view! { <p>"Collapsed content."</p> }.into_any())}
+///         >
+///             <List>
+///                 <Item>{ "Item 1" }</Item>
+///             </List>
+///         </Accordion>
+///     }
+/// }
+/// ```
+///
+/// ## Binding Other Reactive State
+/// ```rust
+/// use leptos::prelude::*;
+/// use accordion_rs::leptos::{Accordion, List, Item};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let open = RwSignal::new(false);
+///     let section = RwSignal::new(None::<&'static str>);
+///     let faq_open = Memo::new(move |_| section.get() == Some("faq"));
+///
+///     view! {
+///         <Accordion
+///             expand=open
+///             expanded={Box::new(|| view! { <p>"Read-write signal (open)"</p> }.into_any())}
+///             collapsed={Box::new(|| view! { <p>"Read-write signal"</p> }.into_any())}
+///         >
+///             <List>
+///                 <Item>{ "Bound to an RwSignal" }</Item>
+///             </List>
+///         </Accordion>
+///         <Accordion
+///             expand=faq_open
+///             on_toggle=Callback::new(move |open: bool| section.set(open.then_some("faq")))
+///             expanded={Box::new(|| view! { <p>"FAQ (open)"</p> }.into_any())}
+///             collapsed={Box::new(|| view! { <p>"FAQ"</p> }.into_any())}
+///         >
+///             <List>
+///                 <Item>{ "Derived from the current section" }</Item>
+///             </List>
+///         </Accordion>
+///     }
+/// }
+/// ```
+///
 /// ## Accordion with Custom Styles
 /// ```rust
 /// use leptos::prelude::*;
@@ -102,9 +197,9 @@ use leptos::prelude::*;
 /// pub fn App() -> impl IntoView {
 ///     let expanded = signal(false);
 ///
-///     let will_open = move || log!("Accordion is about to open.");
+///     let will_open = move |_| log!("Accordion is about to open.");
 ///     let did_open = move || log!("Accordion has opened.");
-///     let will_close = move || log!("Accordion is about to close.");
+///     let will_close = move |_| log!("Accordion is about to close.");
 ///     let did_close = move || log!("Accordion has closed.");
 ///
 ///     view! {
@@ -112,9 +207,9 @@ use leptos::prelude::*;
 ///             expand={expanded}
 ///             expanded={Box::new(|| view! { <p>"Expanded content."</p> }.into_any())}
 ///             collapsed={Box::new(|| view! { <p>"Collapsed content."</p> }.into_any())}
-///             will_open={Callback::from(will_open)}
+///             will_open={Callback::new(will_open)}
 ///             did_open={Callback::from(did_open)}
-///             will_close={Callback::from(will_close)}
+///             will_close={Callback::new(will_close)}
 ///             did_close={Callback::from(did_close)}
 ///         >
 ///             <List>
@@ -126,24 +221,149 @@ use leptos::prelude::*;
 /// }
 /// ```
 ///
+/// ## Accordion with Async Content
+/// ```rust
+/// use leptos::prelude::*;
+/// use accordion_rs::leptos::{Accordion, PanelLoader};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let loader = PanelLoader::new(|| async {
+///         Ok(ViewFn::from(|| view! { <p>"Loaded on first open."</p> }))
+///     });
+///
+///     view! {
+///         <Accordion
+///             expanded={Box::new(|| view! { <p>"Details"</p> }.into_any())}
+///             collapsed={Box::new(|| view! { <p>"Details"</p> }.into_any())}
+///             loader=loader
+///             loading=|| view! { <p>"Loading..."</p> }
+///         >
+///             <p>"Summary"</p>
+///         </Accordion>
+///     }
+/// }
+/// ```
+///
+/// ## Deep-Linked Accordion
+/// ```rust
+/// use leptos::prelude::*;
+/// use accordion_rs::leptos::{Accordion, List, Item};
+/// use accordion_rs::UrlUpdate;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     // Opened by `/faq#faq-shipping` or `/faq?open=faq-shipping`.
+///     view! {
+///         <Accordion
+///             aria_controls="faq-shipping"
+///             deep_link=true
+///             update_url=UrlUpdate::Hash
+///             expanded={Box::new(|| view! { <p>"Shipping takes two days."</p> }.into_any())}
+///             collapsed={Box::new(|| view! { <p>"How long does shipping take?"</p> }.into_any())}
+///         >
+///             <List>
+///                 <Item>{ "Orders ship from our warehouse." }</Item>
+///             </List>
+///         </Accordion>
+///     }
+/// }
+/// ```
+///
+/// ## Nested Accordions
+/// ```rust
+/// use leptos::prelude::*;
+/// use accordion_rs::leptos::{Accordion, Item, List};
+/// use accordion_rs::DescendantPolicy;
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <Accordion
+///             expanded={Box::new(|| view! { <p>"Products"</p> }.into_any())}
+///             collapsed={Box::new(|| view! { <p>"Products"</p> }.into_any())}
+///             descendants=DescendantPolicy::Reset
+///         >
+///             <List>
+///                 <Item>
+///                     // Rendered as an `<h4>`, with the `accordion-depth-1` class.
+///                     <Accordion
+///                         expanded={Box::new(|| view! { <p>"Hardware"</p> }.into_any())}
+///                         collapsed={Box::new(|| view! { <p>"Hardware"</p> }.into_any())}
+///                     >
+///                         <p>"Keyboards, mice and monitors."</p>
+///                     </Accordion>
+///                 </Item>
+///             </List>
+///         </Accordion>
+///     }
+/// }
+/// ```
+///
 /// # Behavior
 /// - The accordion toggles between expanded and collapsed states based on the `expand` signal.
 /// - Transitions between states are smooth, with customizable duration.
 /// - Callbacks allow you to hook into the expand/collapse lifecycle events.
+/// - The lifecycle runs `Closed` → `Opening` → `Open` → `Closing` → `Closed`. `did_open` and `did_close` fire
+///   on the panel's `transitionend`, or after `duration` milliseconds as a fallback.
+/// - The current phase is reported through `on_phase_change` and the container's `data-phase` attribute.
+/// - The panel's height is measured and animated between `0` and its content height over `duration` milliseconds.
+/// - The `mount` policy decides whether a closed panel is unmounted or kept `hidden` and `inert`, preserving its state.
+/// - With a `persist_key`, the expanded state is saved to web storage and restored once the accordion runs
+///   in the browser. Without storage, the accordion behaves as if no key were given.
+/// - With `deep_link`, an accordion linked by the URL's hash or `open` query parameter opens like a header click and scrolls into view;
+///   `update_url` keeps the URL in sync through `history.replaceState`, leaving the path and the history state untouched.
+/// - A `loader` runs through a `LocalResource` on first expand inside a `Suspense` showing `loading`; failures
+///   render `error`, which can retry the load.
+/// - Accordions nested in a panel read the parent's `AccordionParent` context. Their depth defaults the heading
+///   level, indentation class and generated ids, and they join an `AccordionGroup` only at their own depth.
+///   Uncontrolled nested accordions keep their state while the parent is closed, or go back to
+///   `default_expanded` with `DescendantPolicy::Reset`.
+/// - Without `expand`, the accordion is uncontrolled: it owns its state, starting from `default_expanded`.
+/// - Inside an `AccordionGroup`, the group owns the expansion state and `expand` is ignored.
+/// - A `disabled` accordion ignores header clicks and keeps its expand state; the header gets `aria-disabled`
+///   and `disabled_class`/`disabled_style`.
 /// - ARIA attributes can be toggled for better accessibility when `aria_enabled` is `true`.
+/// - The header and panel get ids, generated from a counter when `aria_controls` is empty. The header's
+///   `aria-controls` points at the panel, which has `role="region"` and `aria-labelledby` pointing back.
+///   Call `reset_ids` before each server render to keep generated ids stable through hydration.
+/// - The header is a `<button>` inside an `<h1>`–`<h6>` heading chosen by `heading_level`, following the
+///   WAI-ARIA accordion pattern.
+/// - Headers follow the WAI-ARIA accordion keyboard pattern: `Enter`/`Space` toggles,
+///   `ArrowUp`/`ArrowDown` move focus to the previous/next sibling accordion header (wrapping), and
+///   `Home`/`End` move it to the first/last one.
 ///
 /// # Notes
 /// - The `size` property determines the overall size of the accordion (e.g., `Size::Small`, `Size::Medium`).
 /// - Use inline styles or CSS classes for detailed customization of the accordion's appearance.
 /// - Default callbacks (`will_open`, `did_open`, `will_close`, `did_close`) are no-ops but can be customized as needed.
+/// - `will_open` and `will_close` receive a `ToggleEvent`; calling `prevent_default` on it cancels the toggle.
 #[component]
 pub fn Accordion(
-    /// Signal to track if the accordion is expanded.
+    /// Reactive state tracking whether the accordion is expanded.
+    ///
+    /// Accepts anything converting into an `ExpandState`: an `RwSignal<bool>`, a
+    /// `(ReadSignal<bool>, WriteSignal<bool>)` tuple, a read-only `Signal`, `Memo` or `ReadSignal`,
+    /// or any of those paired with a `WriteSignal<bool>` or `Callback<bool>` setter. Use this to
+    /// programmatically control or react to the accordion's expansion. When omitted, the accordion
+    /// keeps its own state, or follows its `AccordionGroup` if it has one.
+    #[prop(optional, into)]
+    expand: Option<ExpandState>,
+
+    /// A controller handle created by `use_accordion`.
     ///
-    /// This is a tuple containing a `ReadSignal` to observe the expanded state
-    /// and a `WriteSignal` to update it. Use this to programmatically control or
-    /// react to the accordion's expansion.
-    expand: (ReadSignal<bool>, WriteSignal<bool>),
+    /// Replaces `expand`: the accordion follows the handle's state, and the handle's `open`, `close`
+    /// and `toggle` run like a header click, firing `will_open`/`will_close` and `did_open`/`did_close`.
+    /// Defaults to `None`.
+    #[prop(optional)]
+    handle: Option<AccordionHandle>,
+
+    /// Whether an uncontrolled accordion starts expanded.
+    ///
+    /// Only read when the accordion is created, and only when `expand` is not provided.
+    /// Defaults to `false`.
+    #[prop(default = false)]
+    default_expanded: bool,
 
     /// Content to display when the accordion is expanded.
     ///
@@ -165,208 +385,1336 @@ pub fn Accordion(
     /// Size of the accordion.
     ///
     /// This defines the overall size of the accordion component. Acceptable values
-    /// are defined by the `Size` enum, and the default value is `Size::XXLarge`.
-    #[prop(default = Size::XXLarge)]
-    size: Size,
+    /// are defined by the `Size` enum. Defaults to the `AccordionConfigProvider`'s size, or `Size::XXLarge`.
+    #[prop(optional)]
+    size: Option<Size>,
 
     /// ARIA controls attribute.
     ///
     /// Sets the value for the `aria-controls` attribute, which is used for accessibility
-    /// purposes to associate the accordion header with its content. Defaults to an empty string.
-    #[prop(default = "")]
-    aria_controls: &'static str,
+    /// purposes to associate the accordion header with its content. It is used as the panel's `id`,
+    /// and the header's `id` appends `-header` to it. Defaults to an empty string, which generates unique ids.
+    #[prop(into, optional)]
+    aria_controls: String,
+
+    /// Shared classes and styles for the accordion, its lists and items.
+    ///
+    /// The instance's own class and style props override the theme's values when they aren't
+    /// empty. Defaults to the parent accordion's theme, then the `AccordionConfigProvider`'s, if any.
+    #[prop(optional)]
+    theme: Option<Theme>,
 
     /// Inline style for the accordion.
     ///
     /// Applies custom inline styles to the accordion container. Defaults to an empty string.
-    #[prop(default = "")]
-    style: &'static str,
+    #[prop(into, optional)]
+    style: String,
 
     /// Style when the accordion is expanded.
     ///
     /// Defines additional inline styles applied to the accordion when it is expanded.
     /// Defaults to an empty string.
-    #[prop(default = "")]
-    expanded_style: &'static str,
+    #[prop(into, optional)]
+    expanded_style: String,
 
     /// Style when the accordion is collapsed.
     ///
     /// Defines additional inline styles applied to the accordion when it is collapsed.
     /// Defaults to an empty string.
-    #[prop(default = "")]
-    collapsed_style: &'static str,
+    #[prop(into, optional)]
+    collapsed_style: String,
+
+    /// Style when the accordion is disabled.
+    ///
+    /// Appended to the header's inline styles while `disabled` is `true`.
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    disabled_style: String,
 
     /// Style for the accordion's content container.
     ///
     /// Sets inline styles for the container that wraps the accordion's content.
     /// Defaults to an empty string.
-    #[prop(default = "")]
-    content_style: &'static str,
+    #[prop(into, optional)]
+    content_style: String,
 
     /// CSS class for the accordion.
     ///
     /// Adds a CSS class to the accordion container for styling purposes. Defaults to an empty string.
-    #[prop(default = "")]
-    class: &'static str,
+    #[prop(into, optional)]
+    class: String,
 
     /// CSS class when the accordion is expanded.
     ///
     /// Adds a CSS class to the accordion container when it is in an expanded state.
     /// Defaults to an empty string.
-    #[prop(default = "")]
-    expanded_class: &'static str,
+    #[prop(into, optional)]
+    expanded_class: String,
 
     /// CSS class when the accordion is collapsed.
     ///
     /// Adds a CSS class to the accordion container when it is in a collapsed state.
     /// Defaults to an empty string.
-    #[prop(default = "")]
-    collapsed_class: &'static str,
+    #[prop(into, optional)]
+    collapsed_class: String,
+
+    /// CSS class when the accordion is disabled.
+    ///
+    /// Adds a CSS class to the header, next to the expanded or collapsed class, while `disabled` is `true`.
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    disabled_class: String,
 
     /// CSS class for the content container.
     ///
     /// Adds a CSS class to the container that wraps the accordion's content. Defaults to an empty string.
-    #[prop(default = "")]
-    content_class: &'static str,
+    #[prop(into, optional)]
+    content_class: String,
 
     /// Whether to include ARIA attributes.
     ///
-    /// If `true`, ARIA attributes are included to improve accessibility. Defaults to `true`.
-    #[prop(default = true)]
-    aria_enabled: bool,
+    /// If `true`, ARIA attributes are included to improve accessibility. Defaults to the
+    /// `AccordionConfigProvider`'s value, or `true`.
+    #[prop(optional)]
+    aria_enabled: Option<bool>,
+
+    /// Level of the heading wrapping the header button.
+    ///
+    /// The header is rendered as `<h1>`–`<h6>`, with values outside `1..=6` clamped.
+    /// Defaults to one level below the parent accordion's heading, or for a top-level accordion the
+    /// `AccordionConfigProvider`'s level, or `3`.
+    #[prop(optional)]
+    heading_level: Option<u8>,
+
+    /// Indentation class for the accordion container.
+    ///
+    /// Defaults to `accordion-depth-N`, where `N` is the nesting depth: `0` for a top-level accordion.
+    #[prop(into, optional)]
+    indent_class: Option<String>,
+
+    /// What happens to accordions nested in the panel when it closes.
+    ///
+    /// Defaults to `DescendantPolicy::Remember`, which keeps the state of uncontrolled nested
+    /// accordions even when the panel is unmounted.
+    #[prop(optional)]
+    descendants: DescendantPolicy,
 
     /// Duration of the expand/collapse transition in milliseconds.
     ///
     /// Sets the time it takes for the accordion to transition between expanded and collapsed states.
-    /// Defaults to `600` milliseconds.
-    #[prop(default = 600)]
-    duration: u64,
+    /// Defaults to the `AccordionConfigProvider`'s duration, or `600` milliseconds.
+    #[prop(optional)]
+    duration: Option<u64>,
+
+    /// Whether the header ignores clicks.
+    ///
+    /// A disabled accordion keeps its current expand state and sets `aria-disabled`.
+    /// Defaults to `false`.
+    #[prop(default = false)]
+    disabled: bool,
+
+    /// Custom expand/collapse indicator shown in the header.
+    ///
+    /// Rotated along with the built-in one while the accordion is expanded.
+    /// Defaults to `None`, which renders a chevron.
+    #[prop(optional, into)]
+    indicator: Option<ViewFn>,
+
+    /// Side of the header content on which the indicator is shown.
+    ///
+    /// Defaults to `Placement::Trailing`.
+    #[prop(default = Placement::Trailing)]
+    indicator_placement: Placement,
+
+    /// Whether the header shows no indicator.
+    ///
+    /// Defaults to `false`.
+    #[prop(optional)]
+    hide_indicator: bool,
+
+    /// Accessible label of the indicator.
+    ///
+    /// Defaults to an empty string, which labels it "Expand" or "Collapse" depending on the state.
+    #[prop(into, optional)]
+    indicator_label: String,
+
+    /// When the content container is present in the DOM.
+    ///
+    /// Closed panels that stay mounted are rendered `hidden` and `inert`.
+    /// Defaults to `MountPolicy::UnmountOnClose`.
+    #[prop(default = MountPolicy::UnmountOnClose)]
+    mount: MountPolicy,
+
+    /// Web storage key under which the expanded state is saved and restored.
+    ///
+    /// The saved state is restored after hydration, so server-rendered HTML always starts from
+    /// `default_expanded`. Nothing is persisted when storage is unavailable. Defaults to `None`.
+    #[prop(into, optional)]
+    persist_key: Option<String>,
+
+    /// Web storage used with `persist_key`.
+    ///
+    /// Defaults to `PersistStorage::Local`.
+    #[prop(optional)]
+    persist_storage: PersistStorage,
+
+    /// Whether the accordion opens when the page URL links to it.
+    ///
+    /// The accordion opens and scrolls its header into view when its id, `aria_controls` or the
+    /// generated one, is the URL's hash or is listed in the comma-separated `open` query parameter.
+    /// The hash is also followed when it changes. Defaults to `false`.
+    #[prop(optional)]
+    deep_link: bool,
+
+    /// How opening and closing the accordion from its header rewrites the page URL.
+    ///
+    /// Defaults to `UrlUpdate::None`.
+    #[prop(optional)]
+    update_url: UrlUpdate,
+
+    /// Async loader for additional panel content.
+    ///
+    /// Runs through a `LocalResource` the first time the accordion opens, and its output is
+    /// rendered after `children` inside a `Suspense`. The result is kept for later openings.
+    /// Defaults to `None`.
+    #[prop(optional)]
+    loader: Option<PanelLoader>,
+
+    /// Placeholder shown while the `loader` is running.
+    ///
+    /// Used as the `Suspense` fallback around the loaded content. Defaults to an empty view.
+    #[prop(optional, into)]
+    loading: ViewFn,
+
+    /// View rendered when the `loader` fails.
+    ///
+    /// Receives the error message and a `retry` callback.
+    /// Defaults to a `role="alert"` message with a retry button.
+    #[prop(optional)]
+    error: Option<Callback<PanelError, AnyView>>,
 
     /// Callback for when the accordion starts opening.
     ///
     /// This callback is invoked at the start of the accordion's expand transition.
+    /// Calling `prevent_default` on the `ToggleEvent` keeps the accordion closed.
     /// Defaults to no-op.
-    #[prop(default = Callback::from(|| {}))]
-    will_open: Callback<()>,
+    #[prop(default = Callback::new(|_| {}))]
+    will_open: Callback<ToggleEvent>,
 
     /// Callback for when the accordion finishes opening.
     ///
-    /// This callback is invoked after the accordion has fully expanded.
-    /// Defaults to no-op.
+    /// This callback is invoked once the panel's transition has ended, or after `duration`
+    /// milliseconds if no transition runs. Defaults to no-op.
     #[prop(default = Callback::from(|| {}))]
     did_open: Callback<()>,
 
     /// Callback for when the accordion starts closing.
     ///
     /// This callback is invoked at the start of the accordion's collapse transition.
+    /// Calling `prevent_default` on the `ToggleEvent` keeps the accordion open.
     /// Defaults to no-op.
-    #[prop(default = Callback::from(|| {}))]
-    will_close: Callback<()>,
+    #[prop(default = Callback::new(|_| {}))]
+    will_close: Callback<ToggleEvent>,
 
     /// Callback for when the accordion finishes closing.
     ///
-    /// This callback is invoked after the accordion has fully collapsed.
-    /// Defaults to no-op.
+    /// This callback is invoked once the panel's transition has ended, or after `duration`
+    /// milliseconds if no transition runs. Defaults to no-op.
     #[prop(default = Callback::from(|| {}))]
     did_close: Callback<()>,
+
+    /// Callback for when the accordion enters a new lifecycle phase.
+    ///
+    /// Receives the new `Phase` (`Opening`, `Open`, `Closing` or `Closed`).
+    /// Defaults to no-op.
+    #[prop(default = Callback::new(|_| {}))]
+    on_phase_change: Callback<Phase>,
+
+    /// Callback for when the accordion changes its expansion state.
+    ///
+    /// Receives the new state, whether the change comes from the header, a controller handle,
+    /// a deep link or restored storage. Pair it with a read-only `expand` to keep the state in
+    /// your own model. Defaults to `None`.
+    #[prop(optional)]
+    on_toggle: Option<Callback<bool>>,
 ) -> impl IntoView {
-    let toggle_expansion = move || {
-        if expand.0.get() {
-            will_close.run(());
-            expand.1.set(false);
-            did_close.run(());
+    let parent = use_context::<AccordionParent>();
+    let config = use_context::<AccordionConfig>().unwrap_or_default();
+    let properties = config.properties(duration, size.as_ref(), parent.is_some());
+    let duration = config.duration(duration);
+    let aria_enabled = config.aria_enabled(aria_enabled);
+    let depth = parent.map_or(0, |parent| parent.depth + 1);
+    let member = use_context::<AccordionGroupContext>()
+        .filter(|group| group.depth == depth)
+        .map(|group| {
+            let key = group.register();
+            if default_expanded {
+                group.set_open(key, true);
+            }
+            (group, key)
+        });
+    let nested_index =
+        parent.and_then(|parent| parent.nested.try_update_value(NestedState::register));
+    let remembered = parent
+        .zip(nested_index)
+        .and_then(|(parent, index)| parent.nested.with_value(|nested| nested.remembered(index)));
+    let expand = handle
+        .map(|handle| ExpandState::from(handle.expanded))
+        .or(expand);
+    let uncontrolled = expand.is_none();
+    let expand =
+        expand.unwrap_or_else(|| RwSignal::new(remembered.unwrap_or(default_expanded)).into());
+    if let (Some(parent), Some(index), true) = (parent, nested_index, uncontrolled) {
+        Effect::new(move |_| {
+            let expanded = expand.get();
+            parent
+                .nested
+                .update_value(|nested| nested.remember(index, expanded));
+        });
+    }
+
+    let generated_id = AccordionIds::generate(
+        parent
+            .map(|parent| parent.id())
+            .as_deref()
+            .zip(nested_index),
+    );
+    let ids = AccordionIds::new(&aria_controls, &generated_id);
+    if let Some((group, key)) = member {
+        group
+            .members
+            .update_value(|members| members.set_id(key, &ids.base));
+        on_cleanup(move || group.unregister(key));
+    }
+    let heading_level =
+        config.heading_level(heading_level, parent.map(|parent| parent.heading_level));
+    let themed = theme
+        .or_else(|| parent.and_then(|parent| parent.theme()))
+        .or_else(|| config.theme.clone());
+    let theme = themed.clone().unwrap_or_default();
+    let style = Theme::pick(&style, &theme.style).to_string();
+    let class = Theme::pick(&class, &theme.class).to_string();
+    let expanded_style = Theme::pick(&expanded_style, &theme.expanded_style).to_string();
+    let expanded_class = Theme::pick(&expanded_class, &theme.expanded_class).to_string();
+    let collapsed_style = Theme::pick(&collapsed_style, &theme.collapsed_style).to_string();
+    let collapsed_class = Theme::pick(&collapsed_class, &theme.collapsed_class).to_string();
+    let content_style = Theme::pick(&content_style, &theme.content_style).to_string();
+    let content_class = Theme::pick(&content_class, &theme.content_class).to_string();
+    let header_class = theme.header_class.clone();
+    let header_style = theme.header_style.clone();
+    let context = AccordionParent {
+        depth,
+        id: StoredValue::new(ids.base.clone()),
+        heading_level,
+        theme: StoredValue::new(themed),
+        resets: RwSignal::new(0),
+        nested: StoredValue::new(NestedState::default()),
+    };
+
+    let is_expanded = move || match member {
+        Some((group, key)) => group.is_open(key),
+        None => expand.get(),
+    };
+
+    let set_expanded = move |value: bool| {
+        match member {
+            Some((group, key)) => group.set_open(key, value),
+            None => expand.set(value),
+        }
+        if let Some(on_toggle) = on_toggle {
+            on_toggle.run(value);
+        }
+    };
+
+    if let Some(parent) = parent {
+        let seen_resets = StoredValue::new(parent.resets.get_untracked());
+        Effect::new(move |_| {
+            let resets = parent.resets.get();
+            if seen_resets.get_value() != resets {
+                seen_resets.set_value(resets);
+                set_expanded(default_expanded);
+                context.resets.update(|resets| *resets += 1);
+            }
+        });
+    }
+
+    if let Some(key) = persist_key {
+        Effect::new(move |restored: Option<()>| {
+            let expanded = is_expanded();
+            if restored.is_none() {
+                if let Some(saved) = persist_storage
+                    .load(&key)
+                    .and_then(|saved| saved.parse::<bool>().ok())
+                {
+                    if saved != expanded {
+                        set_expanded(saved);
+                    }
+                    return;
+                }
+            }
+            persist_storage.save(&key, &expanded.to_string());
+        });
+    }
+
+    let phase = RwSignal::new(Phase::at_rest(untrack(is_expanded)));
+    let fallback = StoredValue::new(None::<TimeoutHandle>);
+    on_cleanup(move || {
+        if let Some(handle) = fallback.try_get_value().flatten() {
+            handle.clear();
+        }
+    });
+    let read_only = member.is_none() && expand.is_read_only();
+    let current_phase = move || {
+        let expanded = is_expanded();
+        let phase = phase.get();
+        if phase.is_expanded() == expanded {
+            phase
+        } else if read_only && expanded {
+            // The owner changed a read-only state; its transition starts from an effect.
+            Phase::Opening
+        } else if read_only {
+            Phase::Closing
         } else {
-            will_open.run(());
-            expand.1.set(true);
+            Phase::at_rest(expanded)
+        }
+    };
+
+    let opened_before = StoredValue::new(false);
+    let is_mounted = move || {
+        let phase = current_phase();
+        if phase != Phase::Closed {
+            opened_before.set_value(true);
+        }
+        let mounted = mount.is_mounted(phase, opened_before.get_value());
+        if !mounted {
+            context.nested.update_value(NestedState::restart);
+        }
+        mounted
+    };
+    let is_hidden = move || current_phase() == Phase::Closed;
+
+    let was_closed = StoredValue::new(untrack(is_hidden));
+    Effect::new(move |_| {
+        let closed = is_hidden();
+        if closed && !was_closed.get_value() && descendants == DescendantPolicy::Reset {
+            context.nested.update_value(NestedState::forget);
+            context.resets.update(|resets| *resets += 1);
+        }
+        was_closed.set_value(closed);
+    });
+
+    let load_requested = RwSignal::new(false);
+    Effect::new(move |_| {
+        if current_phase() != Phase::Closed {
+            load_requested.set(true);
+        }
+    });
+    let load_generation = RwSignal::new(0u32);
+    let loaded = loader.map(|loader| {
+        LocalResource::new(move || {
+            load_generation.track();
+            let load = load_requested.get().then(|| loader.load());
+            async move {
+                match load {
+                    Some(load) => Some(load.await),
+                    None => None,
+                }
+            }
+        })
+    });
+    let loaded_content = move || {
+        loaded.map(|loaded| {
+            let retry =
+                Callback::new(move |_| load_generation.update(|generation| *generation += 1));
+            let loading = loading.clone();
+            view! {
+                <Suspense fallback=move || loading.run()>
+                    {move || Suspend::new(async move {
+                        match loaded.await {
+                            Some(Ok(content)) => content.run(),
+                            Some(Err(message)) => {
+                                let error_view = error.unwrap_or(Callback::new(default_error_view));
+                                error_view.run(PanelError { message, retry })
+                            }
+                            None => ().into_any(),
+                        }
+                    })}
+                </Suspense>
+            }
+        })
+    };
+
+    let panel = NodeRef::<Div>::new();
+    let measured_height = RwSignal::new(None::<f64>);
+    Effect::new(move |_| {
+        let measure = move || {
+            panel
+                .get_untracked()
+                .map(|panel| panel.scroll_height() as f64)
+        };
+        match (current_phase(), measured_height.get()) {
+            (Phase::Opening | Phase::Closing, None) => measured_height.set(measure()),
+            (Phase::Closing, Some(height)) if height > 0.0 => {
+                // Reading the layout commits the measured height before collapsing to zero.
+                measure();
+                measured_height.set(Some(0.0));
+            }
+            _ => {}
+        }
+    });
+
+    let finish_transition = move |settled: Phase| {
+        phase.set(settled);
+        on_phase_change.run(settled);
+        if settled == Phase::Open {
             did_open.run(());
+        } else {
+            did_close.run(());
+        }
+    };
+
+    let start_transition = move |expanded: bool| {
+        context
+            .id
+            .with_value(|id| update_url.update_location(id, expanded));
+        let next = if expanded {
+            Phase::Opening
+        } else {
+            Phase::Closing
+        };
+        phase.set(next);
+        measured_height.set(None);
+        on_phase_change.run(next);
+
+        if let Some(handle) = fallback.get_value() {
+            handle.clear();
         }
+        let handle = set_timeout_with_handle(
+            move || finish_transition(next.settled()),
+            Duration::from_millis(duration),
+        );
+        fallback.set_value(handle.ok());
     };
 
+    if read_only {
+        Effect::new(move |previous: Option<bool>| {
+            let expanded = expand.get();
+            if previous.is_some_and(|previous| previous != expanded)
+                && phase.get_untracked().is_expanded() != expanded
+            {
+                start_transition(expanded);
+            }
+            expanded
+        });
+    }
+
+    let toggle_expansion = move || {
+        if disabled {
+            return;
+        }
+
+        let expanded = untrack(is_expanded);
+        let event = ToggleEvent::new(!expanded);
+        if expanded {
+            will_close.run(event.clone());
+        } else {
+            will_open.run(event.clone());
+        }
+        if event.default_prevented() {
+            return;
+        }
+
+        if read_only {
+            // The transition starts once the owner updates the state.
+            if let Some(on_toggle) = on_toggle {
+                on_toggle.run(!expanded);
+            }
+            return;
+        }
+        set_expanded(!expanded);
+        if untrack(is_expanded) != expanded {
+            start_transition(!expanded);
+        }
+    };
+
+    if let Some(handle) = handle {
+        let seen_request = StoredValue::new(handle.request.get_untracked().seq);
+        Effect::new(move |_| {
+            let request = handle.request.get();
+            if seen_request.get_value() != request.seq {
+                seen_request.set_value(request.seq);
+                untrack(|| {
+                    if request.action.applies(is_expanded()) {
+                        toggle_expansion();
+                    }
+                });
+            }
+        });
+        Effect::new(move |_| handle.phase.set(current_phase()));
+    }
+
+    if deep_link {
+        let ids = ids.clone();
+        Effect::new(move |_| {
+            let ids = ids.clone();
+            let open_linked = move || {
+                if ids.is_linked(&location_links()) {
+                    if !untrack(is_expanded) {
+                        toggle_expansion();
+                    }
+                    scroll_into_view(&ids.header);
+                }
+            };
+            open_linked();
+            let listener = window_event_listener(leptos::ev::hashchange, move |_| open_linked());
+            on_cleanup(move || listener.remove());
+        });
+    }
+
+    let on_header_keydown = move |e: leptos::ev::KeyboardEvent| {
+        if e.target() != e.current_target() {
+            return;
+        }
+        let Some(key) = HeaderKey::from_key(&e.key()) else {
+            return;
+        };
+        e.prevent_default();
+        focus_sibling_header(&event_target::<Element>(&e), key);
+    };
+
+    let indicator = (!hide_indicator).then(|| {
+        view! {
+            <span
+                class=INDICATOR_CLASS
+                role=aria_enabled.then_some("img")
+                aria-label=move || {
+                    aria_enabled.then(|| label_indicator(&indicator_label, is_expanded()).to_string())
+                }
+                data-placement=indicator_placement.as_str()
+                data-expanded=move || is_expanded().then_some("")
+                style=move || indicator_style(indicator_placement, is_expanded())
+            >
+                {indicator.map_or_else(chevron, |indicator| indicator.run())}
+            </span>
+        }
+    });
+    let (leading_indicator, trailing_indicator) = match indicator_placement {
+        Placement::Leading => (indicator, None),
+        Placement::Trailing => (None, indicator),
+    };
+
+    let header = view! {
+        <button
+            type="button"
+            id=ids.header.clone()
+            aria-expanded={move || if aria_enabled { Some(is_expanded().to_string()) } else { None }}
+            aria-controls=aria_enabled.then(|| ids.panel.clone())
+            aria-disabled=(aria_enabled && disabled).then_some("true")
+            data-accordion-header=""
+            data-disabled=disabled.then_some("")
+            on:click=move |_| toggle_expansion()
+            on:keydown=on_header_keydown
+            class=move || format!(
+                "{HEADER_CLASS} {} {} {}",
+                header_class,
+                if is_expanded() { expanded_class.as_str() } else { collapsed_class.as_str() },
+                if disabled { disabled_class.as_str() } else { "" }
+            )
+            style=move || format!(
+                "{HEADER_STYLE} cursor: {}; {} {} {}",
+                if disabled { "not-allowed" } else { "pointer" },
+                header_style,
+                if is_expanded() { expanded_style.as_str() } else { collapsed_style.as_str() },
+                if disabled { disabled_style.as_str() } else { "" }
+            )
+        >
+            {leading_indicator}
+            {move || {
+                if is_expanded() {
+                    expanded()
+                } else {
+                    collapsed()
+                }
+            }}
+            {trailing_indicator}
+        </button>
+    };
+    let heading = match heading_level {
+        1 => view! { <h1 class=HEADING_CLASS style=HEADING_STYLE>{header}</h1> }.into_any(),
+        2 => view! { <h2 class=HEADING_CLASS style=HEADING_STYLE>{header}</h2> }.into_any(),
+        3 => view! { <h3 class=HEADING_CLASS style=HEADING_STYLE>{header}</h3> }.into_any(),
+        4 => view! { <h4 class=HEADING_CLASS style=HEADING_STYLE>{header}</h4> }.into_any(),
+        5 => view! { <h5 class=HEADING_CLASS style=HEADING_STYLE>{header}</h5> }.into_any(),
+        _ => view! { <h6 class=HEADING_CLASS style=HEADING_STYLE>{header}</h6> }.into_any(),
+    };
+
+    let on_transition_end = move |e: leptos::ev::TransitionEvent| {
+        let phase = untrack(current_phase);
+        if phase.is_transitioning()
+            && e.target() == e.current_target()
+            && e.property_name() == "height"
+        {
+            if let Some(handle) = fallback.get_value() {
+                handle.clear();
+            }
+            finish_transition(phase.settled());
+        }
+    };
+
+    let size_rules = config.size_rules(size.as_ref(), &ids.base);
+    let size_id = size_rules.is_some().then(|| ids.base.clone());
+
     view! {
         <div
-            style=format!("{} {}", size.to_style(), style)
-            class=class
+            style=format!("{ROOT_STYLE} {properties}{style}")
+            class=format!(
+                "{ROOT_CLASS} {} {}",
+                class,
+                indent_class.unwrap_or_else(|| depth_class(depth))
+            )
+            data-phase=move || current_phase().as_str()
+            data-accordion-id=size_id
         >
-            <div
-                aria-expanded={move || if aria_enabled { Some(expand.0.get().to_string()) } else { None }}
-                aria-controls=aria_controls
-                on:click=move |_| toggle_expansion()
-                class=move || if expand.0.get() { expanded_class } else { collapsed_class }
-                style=move || format!(
-                    "cursor: pointer; transition: all {}ms; {}",
-                    duration,
-                    if expand.0.get() { expanded_style } else { collapsed_style }
-                )
-            >
-                {move || {
-                    if expand.0.get() {
-                        expanded()
-                    } else {
-                        collapsed()
-                    }
-                }}
-            </div>
-            <Show when=move || expand.0.get() clone:children>
+            {size_rules.map(|rules| view! { <style>{rules}</style> })}
+            {heading}
+            <Show when=is_mounted clone:children>
                 <div
-                    id=aria_controls
-                    class=content_class
-                    style=format!(
-                        "overflow: hidden; transition: all {}ms; {}",
-                        duration,
-                        content_style
-                    )
+                    node_ref=panel
+                    id=ids.panel.clone()
+                    role=aria_enabled.then_some("region")
+                    aria-labelledby=aria_enabled.then(|| ids.header.clone())
+                    class=format!("{PANEL_CLASS} {content_class}")
+                    hidden=is_hidden
+                    inert=is_hidden
+                    on:transitionend=on_transition_end
+                    style={
+                        let content_style = content_style.clone();
+                        move || format!(
+                            "{PANEL_STYLE} {} {}",
+                            panel_height(current_phase(), measured_height.get()),
+                            content_style
+                        )
+                    }
                 >
-                    {children()}
+                    <Provider value=context clone:children clone:loaded_content>
+                        {children()}
+                        {loaded_content.clone()}
+                    </Provider>
                 </div>
             </Show>
         </div>
     }
 }
 
+/// Reactive expansion state an `Accordion` binds to through its `expand` prop.
+///
+/// Built from the common Leptos reactive types with `From`/`Into`. A state without a setter is
+/// read-only: after `will_open`/`will_close`, a toggle only requests the change through `on_toggle`.
+/// The accordion animates whenever the owner updates the state, firing `did_open`/`did_close`.
+#[derive(Clone, Copy)]
+pub struct ExpandState {
+    value: Signal<bool>,
+    setter: Option<Callback<bool>>,
+}
+
+impl ExpandState {
+    /// Creates a state read from `value` and updated through `setter`.
+    pub fn new(value: impl Into<Signal<bool>>, setter: Option<Callback<bool>>) -> Self {
+        Self {
+            value: value.into(),
+            setter,
+        }
+    }
+
+    /// Whether the accordion is expanded, tracking the state in a reactive context.
+    pub fn get(&self) -> bool {
+        self.value.get()
+    }
+
+    /// Updates the state, if it has a setter.
+    pub fn set(&self, expanded: bool) {
+        if let Some(setter) = self.setter {
+            setter.run(expanded);
+        }
+    }
+
+    /// Whether the state has no setter, leaving changes to its owner.
+    pub(crate) fn is_read_only(&self) -> bool {
+        self.setter.is_none()
+    }
+}
+
+impl From<RwSignal<bool>> for ExpandState {
+    fn from(signal: RwSignal<bool>) -> Self {
+        Self::new(signal, Some(Callback::new(move |value| signal.set(value))))
+    }
+}
+
+impl From<Signal<bool>> for ExpandState {
+    fn from(value: Signal<bool>) -> Self {
+        Self::new(value, None)
+    }
+}
+
+impl From<ReadSignal<bool>> for ExpandState {
+    fn from(value: ReadSignal<bool>) -> Self {
+        Self::new(value, None)
+    }
+}
+
+impl From<Memo<bool>> for ExpandState {
+    fn from(value: Memo<bool>) -> Self {
+        Self::new(value, None)
+    }
+}
+
+impl<R: Into<Signal<bool>>> From<(R, WriteSignal<bool>)> for ExpandState {
+    fn from((value, setter): (R, WriteSignal<bool>)) -> Self {
+        Self::new(
+            value,
+            Some(Callback::new(move |expanded| setter.set(expanded))),
+        )
+    }
+}
+
+impl<R: Into<Signal<bool>>> From<(R, Callback<bool>)> for ExpandState {
+    fn from((value, setter): (R, Callback<bool>)) -> Self {
+        Self::new(value, Some(setter))
+    }
+}
+
+/// Controller handle of an `Accordion`, created by `use_accordion`.
+///
+/// Passed to an accordion's `handle` prop, it lets code outside the accordion open and close it
+/// the way a header click does: `will_open`/`will_close` can cancel the change, the panel animates,
+/// and `did_open`/`did_close` fire once it settles.
+#[derive(Clone, Copy)]
+pub struct AccordionHandle {
+    expanded: RwSignal<bool>,
+    phase: RwSignal<Phase>,
+    request: RwSignal<ControlRequest>,
+}
+
+impl AccordionHandle {
+    /// Opens the accordion, unless it is already open.
+    pub fn open(&self) {
+        self.request(ControlAction::Open);
+    }
+
+    /// Closes the accordion, unless it is already closed.
+    pub fn close(&self) {
+        self.request(ControlAction::Close);
+    }
+
+    /// Opens a closed accordion, or closes an open one.
+    pub fn toggle(&self) {
+        self.request(ControlAction::Toggle);
+    }
+
+    /// Whether the accordion is expanded.
+    ///
+    /// Tracks the state when read in a reactive context.
+    pub fn is_open(&self) -> bool {
+        self.expanded.get()
+    }
+
+    /// Current lifecycle phase of the accordion.
+    ///
+    /// Tracks the phase when read in a reactive context.
+    pub fn phase(&self) -> Phase {
+        self.phase.get()
+    }
+
+    fn request(&self, action: ControlAction) {
+        self.request
+            .update(|request| *request = request.next(action));
+    }
+}
+
+/// Creates a controller handle for an `Accordion`, starting expanded if `default_expanded` is set.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use accordion_rs::leptos::{use_accordion, Accordion, List, Item};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     let handle = use_accordion(false);
+///
+///     view! {
+///         <a href="#details" on:click=move |_| handle.open()>"Show details"</a>
+///         <Accordion
+///             handle=handle
+///             expanded={Box::new(|| view! { <p>"Details (open)"</p> }.into_any())}
+///             collapsed={Box::new(|| view! { <p>"Details"</p> }.into_any())}
+///         >
+///             <List>
+///                 <Item>{ "More details" }</Item>
+///             </List>
+///         </Accordion>
+///     }
+/// }
+/// ```
+pub fn use_accordion(default_expanded: bool) -> AccordionHandle {
+    AccordionHandle {
+        expanded: RwSignal::new(default_expanded),
+        phase: RwSignal::new(Phase::at_rest(default_expanded)),
+        request: RwSignal::new(ControlRequest::default()),
+    }
+}
+
+/// Moves focus from `header` to one of its sibling accordion headers.
+fn focus_sibling_header(header: &Element, key: HeaderKey) {
+    let Some(Ok(nodes)) = header
+        .parent_element()
+        .and_then(|heading| heading.parent_element())
+        .and_then(|accordion| accordion.parent_element())
+        .map(|parent| parent.query_selector_all(SIBLING_HEADERS))
+    else {
+        return;
+    };
+    let headers: Vec<HtmlElement> = (0..nodes.length())
+        .filter_map(|index| nodes.get(index)?.dyn_into().ok())
+        .collect();
+    let target = headers
+        .iter()
+        .position(|node| node.is_same_node(Some(header)))
+        .and_then(|current| key.focus_index(current, headers.len()));
+    if let Some(target) = target {
+        let _ = headers[target].focus();
+    }
+}
+
+/// Future returned by a `PanelLoader`.
+pub type PanelFuture = Pin<Box<dyn Future<Output = Result<ViewFn, String>>>>;
+
+/// Async loader for the content of an accordion's panel.
+///
+/// The loader runs the first time the accordion opens. Its output is kept, so closing and
+/// reopening the accordion doesn't load the content again.
+#[derive(Clone)]
+pub struct PanelLoader(Arc<dyn Fn() -> PanelFuture + Send + Sync>);
+
+impl PanelLoader {
+    /// Creates a loader from an async function producing the panel content or an error message.
+    pub fn new<F, Fut>(load: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<ViewFn, String>> + 'static,
+    {
+        Self(Arc::new(move || Box::pin(load())))
+    }
+
+    fn load(&self) -> PanelFuture {
+        (self.0)()
+    }
+}
+
+/// A failed `PanelLoader` run, passed to the accordion's `error` view.
+#[derive(Clone)]
+pub struct PanelError {
+    /// The error message returned by the loader.
+    pub message: String,
+
+    /// Runs the loader again.
+    pub retry: Callback<()>,
+}
+
+/// Built-in indicator, a chevron pointing down that turns up while expanded.
+fn chevron() -> AnyView {
+    view! {
+        <svg
+            viewBox="0 0 24 24"
+            width="1em"
+            height="1em"
+            fill="none"
+            stroke="currentColor"
+            stroke-width="2"
+            stroke-linecap="round"
+            stroke-linejoin="round"
+            aria-hidden="true"
+        >
+            <path d="M6 9l6 6 6-6" />
+        </svg>
+    }
+    .into_any()
+}
+
+fn default_error_view(error: PanelError) -> AnyView {
+    view! {
+        <div role="alert">
+            {error.message}
+            <button type="button" on:click=move |_| error.retry.run(())>
+                "Retry"
+            </button>
+        </div>
+    }
+    .into_any()
+}
+
+/// Context provided by an accordion to the content of its panel.
+///
+/// Nested accordions read it to find their depth, default their heading level, indentation
+/// class and ids, and remember or reset their state when the parent closes.
+#[derive(Clone, Copy)]
+pub struct AccordionParent {
+    depth: usize,
+    id: StoredValue<String>,
+    heading_level: u8,
+    theme: StoredValue<Option<Theme>>,
+    resets: RwSignal<usize>,
+    nested: StoredValue<NestedState>,
+}
+
+impl AccordionParent {
+    /// Nesting depth of the parent accordion: `0` for a top-level accordion.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Base id of the parent accordion, prefixing the ids generated for nested accordions.
+    pub fn id(&self) -> String {
+        self.id.get_value()
+    }
+
+    /// Heading level of the parent accordion's header.
+    pub fn heading_level(&self) -> u8 {
+        self.heading_level
+    }
+
+    /// Theme of the parent accordion, inherited by the lists, items and accordions inside it.
+    pub fn theme(&self) -> Option<Theme> {
+        self.theme.get_value()
+    }
+}
+
+/// Context shared by an `AccordionGroup` with the accordions rendered inside it.
+///
+/// `expand_all`, `collapse_all` and `toggle` change the group's state directly: they skip the
+/// accordions' `will_open`/`will_close` and `did_open`/`did_close` callbacks, and the panels
+/// switch without animating. So does an accordion that `single` mode closes when another opens.
+#[derive(Clone, Copy)]
+pub struct AccordionGroupContext {
+    state: RwSignal<GroupState>,
+    depth: usize,
+    single: bool,
+    members: StoredValue<GroupMembers>,
+}
+
+impl AccordionGroupContext {
+    /// Hands out the key of the next accordion joining the group.
+    fn register(&self) -> usize {
+        self.members
+            .try_update_value(GroupMembers::register)
+            .unwrap_or_default()
+    }
+
+    /// Removes the accordion registered under `key`, closing it so it no longer counts as open.
+    ///
+    /// Does nothing once the group itself is disposed.
+    fn unregister(&self, key: usize) {
+        self.members
+            .try_update_value(|members| members.unregister(key));
+        self.state.try_update(|state| state.close(key));
+    }
+
+    /// Whether the accordion registered under `key` is open.
+    pub fn is_open(&self, key: usize) -> bool {
+        self.state.with(|state| state.is_open(key))
+    }
+
+    /// Opens or closes the accordion registered under `key`, honoring `single` mode.
+    pub fn set_open(&self, key: usize, open: bool) {
+        self.state
+            .update(|state| state.set_open(key, open, self.single));
+    }
+
+    /// Opens every accordion of the group, or only the first one in `single` mode.
+    pub fn expand_all(&self) {
+        self.set_all(true);
+    }
+
+    /// Closes every accordion of the group.
+    pub fn collapse_all(&self) {
+        self.set_all(false);
+    }
+
+    fn set_all(&self, open: bool) {
+        let keys = self.members.with_value(GroupMembers::keys);
+        self.state
+            .update(|state| state.set_all(&keys, open, self.single));
+    }
+
+    /// Opens or closes the accordion whose id, `aria_controls` or the generated one, is `id`.
+    ///
+    /// Ids that don't belong to the group are ignored.
+    pub fn toggle(&self, id: &str) {
+        if let Some(key) = self.members.with_value(|members| members.key(id)) {
+            self.set_open(key, !untrack(|| self.is_open(key)));
+        }
+    }
+
+    /// Whether all, none or some of the group's accordions are open.
+    ///
+    /// Tracks the group's state when read in a reactive context.
+    pub fn status(&self) -> GroupStatus {
+        let keys = self.members.with_value(GroupMembers::keys);
+        self.state.with(|state| state.status(&keys))
+    }
+}
+
+/// AccordionGroup Component
+///
+/// A Leptos component that owns the expansion state of every `Accordion` rendered inside it,
+/// so the accordions don't need an `expand` signal each. With `single` set, opening one
+/// accordion closes the others.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use accordion_rs::leptos::{Accordion, AccordionGroup};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <AccordionGroup single=true>
+///             <Accordion
+///                 expanded={Box::new(|| view! { <p>"First (open)"</p> }.into_any())}
+///                 collapsed={Box::new(|| view! { <p>"First"</p> }.into_any())}
+///             >
+///                 <p>"First body"</p>
+///             </Accordion>
+///             <Accordion
+///                 expanded={Box::new(|| view! { <p>"Second (open)"</p> }.into_any())}
+///                 collapsed={Box::new(|| view! { <p>"Second"</p> }.into_any())}
+///             >
+///                 <p>"Second body"</p>
+///             </Accordion>
+///         </AccordionGroup>
+///     }
+/// }
+/// ```
+#[component]
+pub fn AccordionGroup(
+    /// The accordions coordinated by the group.
+    ///
+    /// Accordions rendered below the group join it, except those nested in the panel of another accordion.
+    children: Children,
+
+    /// Whether at most one accordion can be open at a time.
+    ///
+    /// If `true`, opening an accordion closes every other accordion in the group.
+    /// Defaults to `false`.
+    #[prop(default = false)]
+    single: bool,
+
+    /// Web storage key under which the group's open accordions are saved and restored.
+    ///
+    /// Accordions are identified by their order in the group. Defaults to `None`.
+    #[prop(into, optional)]
+    persist_key: Option<String>,
+
+    /// Web storage used with `persist_key`.
+    ///
+    /// Defaults to `PersistStorage::Local`.
+    #[prop(optional)]
+    persist_storage: PersistStorage,
+
+    /// Inline style for the group container.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    style: String,
+
+    /// CSS class for the group container.
+    ///
+    /// Defaults to an empty string.
+    #[prop(into, optional)]
+    class: String,
+) -> impl IntoView {
+    let context = AccordionGroupContext {
+        state: RwSignal::new(GroupState::default()),
+        depth: use_context::<AccordionParent>().map_or(0, |parent| parent.depth + 1),
+        single,
+        members: StoredValue::new(GroupMembers::default()),
+    };
+
+    if let Some(key) = persist_key {
+        let state = context.state;
+        let members = context.members;
+        Effect::new(move |restored: Option<()>| {
+            let keys = members.with_value(GroupMembers::keys);
+            let encoded = state.with(|state| state.encode(&keys));
+            if restored.is_none() {
+                if let Some(saved) = persist_storage.load(&key) {
+                    state.set(GroupState::decode(&saved, &keys));
+                    return;
+                }
+            }
+            persist_storage.save(&key, &encoded);
+        });
+    }
+
+    view! {
+        <div class=format!("{GROUP_CLASS} {class}") style=style>
+            <Provider value=context>{children()}</Provider>
+        </div>
+    }
+}
+
+/// AccordionConfigProvider Component
+///
+/// A Leptos component supplying default `theme`, `duration`, `size`, `aria_enabled` and
+/// `heading_level` values to every `Accordion` rendered inside it. An accordion's own props
+/// override the provided defaults, and nested providers override the fields they set.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use accordion_rs::leptos::{Accordion, AccordionConfigProvider};
+/// use accordion_rs::{Size, Theme};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <AccordionConfigProvider theme=Theme::dark() duration=300 size=Size::Large heading_level=2>
+///             <Accordion
+///                 expanded={Box::new(|| view! { <p>"Fast (open)"</p> }.into_any())}
+///                 collapsed={Box::new(|| view! { <p>"Fast"</p> }.into_any())}
+///             >
+///                 <p>"Fast body"</p>
+///             </Accordion>
+///             <Accordion
+///                 expanded={Box::new(|| view! { <p>"Slow (open)"</p> }.into_any())}
+///                 collapsed={Box::new(|| view! { <p>"Slow"</p> }.into_any())}
+///                 duration=1000
+///             >
+///                 <p>"Slow body"</p>
+///             </Accordion>
+///         </AccordionConfigProvider>
+///     }
+/// }
+/// ```
+#[component]
+pub fn AccordionConfigProvider(
+    /// The content whose accordions use the provided defaults.
+    children: Children,
+
+    /// Default theme of accordions that don't set one or inherit one from a parent accordion.
+    ///
+    /// Defaults to the surrounding provider's theme, if any.
+    #[prop(optional)]
+    theme: Option<Theme>,
+
+    /// Default animation duration in milliseconds.
+    ///
+    /// Defaults to the surrounding provider's duration, or else `600` milliseconds.
+    #[prop(optional)]
+    duration: Option<u64>,
+
+    /// Default size of the accordions.
+    ///
+    /// Defaults to the surrounding provider's size, or else `Size::XXLarge`.
+    #[prop(optional)]
+    size: Option<Size>,
+
+    /// Whether accordions render ARIA attributes by default.
+    ///
+    /// Defaults to the surrounding provider's value, or else `true`.
+    #[prop(optional)]
+    aria_enabled: Option<bool>,
+
+    /// Default heading level of top-level accordions; nested ones step one level below their parent.
+    ///
+    /// Defaults to the surrounding provider's level, or else `3`.
+    #[prop(optional)]
+    heading_level: Option<u8>,
+) -> impl IntoView {
+    let config = AccordionConfig {
+        theme,
+        duration,
+        size,
+        aria_enabled,
+        heading_level,
+    }
+    .or(use_context::<AccordionConfig>().as_ref());
+
+    view! { <Provider value=config>{children()}</Provider> }
+}
+
 #[component]
 pub fn Item(
     /// Child content of the Item
     children: Children,
 
     /// Additional styles for the Item
-    #[prop(default = "")]
-    style: &'static str,
+    #[prop(into, optional)]
+    style: String,
 
     /// CSS class for the Item
-    #[prop(default = "")]
-    class: &'static str,
+    #[prop(into, optional)]
+    class: String,
 
     /// Alignment for the content
     #[prop(default = Align::Left)]
     align: Align,
 
     /// Title of the Item
-    #[prop(default = "")]
-    title: &'static str,
+    #[prop(into, optional)]
+    title: String,
+
+    /// Optional icon markup for the Item, such as an emoji, an inline SVG or an icon component
+    #[prop(into, optional)]
+    icon: Option<ViewFn>,
 
-    /// Optional icon for the Item
-    #[prop(default = "")]
-    icon: &'static str,
+    /// Side of the title on which the icon is displayed
+    #[prop(optional)]
+    icon_placement: Placement,
+
+    /// Additional class for the `<span>` wrapping the icon, which always has `accordion-item-icon`
+    #[prop(into, optional)]
+    icon_class: String,
+
+    /// Theme supplying the class and style when they are empty, the surrounding accordion's by default
+    #[prop(optional)]
+    theme: Option<Theme>,
 ) -> impl IntoView {
+    let theme = resolve_theme(theme);
+    let style = Theme::pick(&style, &theme.item_style).to_string();
+    let class = Theme::pick(&class, &theme.item_class).to_string();
+    let icon = icon.map(|icon| {
+        view! {
+            <span
+                class=format!("{ITEM_ICON_CLASS} {icon_class}")
+                data-placement=icon_placement.as_str()
+            >
+                {icon.run()}
+            </span>
+        }
+    });
+    let (leading_icon, trailing_icon) = match icon_placement {
+        Placement::Leading => (icon, None),
+        Placement::Trailing => (None, icon),
+    };
+
     view! {
         <li
-            class=class
+            class=format!("{ITEM_CLASS} {class}")
             style=format!("{} {}", align.to_style(), style)
         >
-            {move || {
-                if !icon.is_empty() {
-                    Some(view! { <span class="mr-2">{icon}</span> })
-                } else {
-                    None
-                }
-            }}
+            {leading_icon}
             {move || {
                 if !title.is_empty() {
-                    Some(view! { <strong>{title}</strong> })
+                    Some(view! { <strong>{title.clone()}</strong> })
                 } else {
                     None
                 }
             }}
+            {trailing_icon}
             {children()}
         </li>
     }
@@ -378,36 +1726,151 @@ pub fn Button(
     children: Children,
 
     /// Styles for the Button
-    #[prop(default = "")]
-    style: &'static str,
+    #[prop(into, optional)]
+    style: String,
 
     /// CSS class for the Button
-    #[prop(default = "")]
-    class: &'static str,
+    #[prop(into, optional)]
+    class: String,
+
+    /// Callback for clicks on the Button
+    #[prop(optional)]
+    on_click: Option<Callback<leptos::ev::MouseEvent>>,
 ) -> impl IntoView {
     view! {
-        <button class=class style=style>
+        <button
+            class=format!("{BUTTON_CLASS} {class}")
+            style=style
+            on:click=move |e| {
+                if let Some(on_click) = on_click {
+                    on_click.run(e);
+                }
+            }
+        >
             {children()}
         </button>
     }
 }
 
+/// A `Button` opening every accordion of the surrounding `AccordionGroup`.
+///
+/// Outside of an `AccordionGroup`, the button does nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// use leptos::prelude::*;
+/// use accordion_rs::leptos::{Accordion, AccordionGroup, CollapseAllButton, ExpandAllButton, List, Item};
+///
+/// #[component]
+/// pub fn App() -> impl IntoView {
+///     view! {
+///         <AccordionGroup>
+///             <ExpandAllButton />
+///             <CollapseAllButton>"Hide everything"</CollapseAllButton>
+///             <Accordion
+///                 expanded={Box::new(|| view! { <p>"Installation (open)"</p> }.into_any())}
+///                 collapsed={Box::new(|| view! { <p>"Installation"</p> }.into_any())}
+///             >
+///                 <List>
+///                     <Item>{ "cargo add accordion-rs" }</Item>
+///                 </List>
+///             </Accordion>
+///         </AccordionGroup>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ExpandAllButton(
+    /// Content for the button, `"Expand all"` by default
+    #[prop(optional)]
+    children: Option<Children>,
+
+    /// Styles for the button
+    #[prop(into, optional)]
+    style: String,
+
+    /// CSS class for the button
+    #[prop(into, optional)]
+    class: String,
+) -> impl IntoView {
+    let group = use_context::<AccordionGroupContext>();
+    let on_click = Callback::new(move |_| {
+        if let Some(group) = group {
+            group.expand_all();
+        }
+    });
+
+    view! {
+        <Button class=class style=style on_click=on_click>
+            {children.map_or_else(|| "Expand all".into_any(), |children| children())}
+        </Button>
+    }
+}
+
+/// A `Button` closing every accordion of the surrounding `AccordionGroup`.
+///
+/// Outside of an `AccordionGroup`, the button does nothing.
+#[component]
+pub fn CollapseAllButton(
+    /// Content for the button, `"Collapse all"` by default
+    #[prop(optional)]
+    children: Option<Children>,
+
+    /// Styles for the button
+    #[prop(into, optional)]
+    style: String,
+
+    /// CSS class for the button
+    #[prop(into, optional)]
+    class: String,
+) -> impl IntoView {
+    let group = use_context::<AccordionGroupContext>();
+    let on_click = Callback::new(move |_| {
+        if let Some(group) = group {
+            group.collapse_all();
+        }
+    });
+
+    view! {
+        <Button class=class style=style on_click=on_click>
+            {children.map_or_else(|| "Collapse all".into_any(), |children| children())}
+        </Button>
+    }
+}
+
 #[component]
 pub fn List(
     /// Child items for the List
     children: Children,
 
     /// Styles for the List
-    #[prop(default = "")]
-    style: &'static str,
+    #[prop(into, optional)]
+    style: String,
 
     /// CSS class for the List
-    #[prop(default = "")]
-    class: &'static str,
+    #[prop(into, optional)]
+    class: String,
+
+    /// Theme supplying the class and style when they are empty, the surrounding accordion's by default
+    #[prop(optional)]
+    theme: Option<Theme>,
 ) -> impl IntoView {
+    let theme = resolve_theme(theme);
+    let style = Theme::pick(&style, &theme.list_style).to_string();
+    let class = Theme::pick(&class, &theme.list_class).to_string();
+
     view! {
-        <ul class=class style=style>
+        <ul class=format!("{LIST_CLASS} {class}") style=style>
             {children()}
         </ul>
     }
 }
+
+/// The given theme, or else the theme of the surrounding accordion or `AccordionConfigProvider`.
+fn resolve_theme(theme: Option<Theme>) -> Theme {
+    theme
+        .or_else(|| use_context::<AccordionParent>().and_then(|parent| parent.theme()))
+        .or_else(|| use_context::<AccordionConfig>().and_then(|config| config.theme))
+        .unwrap_or_default()
+}
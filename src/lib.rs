@@ -16,4 +16,8 @@ pub mod dioxus;
 #[cfg(feature = "lep")]
 pub mod leptos;
 
-pub use common::{Align, Size};
+pub use common::{
+    default_stylesheet, reset_ids, AccordionConfig, Align, Breakpoint, DescendantPolicy,
+    Dimensions, GroupStatus, Length, MountPolicy, ParseLengthError, PersistStorage, Phase,
+    Placement, ResponsiveSize, Size, Theme, ToggleEvent, UrlUpdate,
+};
@@ -1,5 +1,21 @@
-use crate::common::{Align, Size};
+use crate::common::{
+    depth_class, indicator_label, indicator_style, location_links, panel_height, scroll_into_view,
+    AccordionConfig, AccordionIds, Align, ControlAction, ControlRequest, DescendantPolicy,
+    GroupMembers, GroupState, GroupStatus, HeaderKey, MountPolicy, NestedState, PersistStorage,
+    Phase, Placement, Size, Theme, ToggleEvent, UrlUpdate, BUTTON_CLASS, GROUP_CLASS, HEADER_CLASS,
+    HEADER_STYLE, HEADING_CLASS, HEADING_STYLE, INDICATOR_CLASS, ITEM_CLASS, ITEM_ICON_CLASS,
+    LIST_CLASS, PANEL_CLASS, PANEL_STYLE, ROOT_CLASS, ROOT_STYLE, SIBLING_HEADERS,
+};
+use gloo_timers::callback::Timeout;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
 use yew::prelude::*;
+use yew::suspense::{Suspense, Suspension};
 
 /// Properties for the Accordion component.
 #[derive(Properties, Clone, PartialEq)]
@@ -7,8 +23,23 @@ pub struct AccordionProps {
     /// A state handle that manages the expansion state of the accordion.
     ///
     /// This property determines whether the accordion is initially expanded or collapsed.
-    /// It is a required prop and should be used to toggle the accordion's expanded state.
-    pub expand: UseStateHandle<bool>,
+    /// When omitted, the accordion keeps its own state, or follows its `AccordionGroup` if it has one.
+    #[prop_or_default]
+    pub expand: Option<UseStateHandle<bool>>,
+
+    /// A controller handle created by `use_accordion`.
+    ///
+    /// Replaces `expand`: the accordion follows the handle's state, and the handle's `open`, `close`
+    /// and `toggle` run like a header click, firing `will_open`/`will_close` and `did_open`/`did_close`.
+    /// Defaults to `None`.
+    #[prop_or_default]
+    pub handle: Option<AccordionHandle>,
+
+    /// Whether an uncontrolled accordion starts expanded.
+    ///
+    /// Only read on the first render, and only when `expand` is not provided. Defaults to `false`.
+    #[prop_or_default]
+    pub default_expanded: bool,
 
     /// The content to be displayed when the accordion is expanded.
     ///
@@ -30,99 +61,236 @@ pub struct AccordionProps {
 
     /// Size of the accordion.
     ///
-    /// Defines the size of the accordion component, such as "small", "medium", or "large". Defaults to `Size::XXLarge`.
+    /// Defines the size of the accordion component, such as "small", "medium", or "large". Defaults to the
+    /// `AccordionConfigProvider`'s size, or `Size::XXLarge`.
     #[prop_or_default]
-    pub size: Size,
+    pub size: Option<Size>,
 
     /// ARIA controls attribute for accessibility.
     ///
-    /// Provides an accessibility feature for screen readers, linking the accordion with other elements. Defaults to an empty string.
+    /// Provides an accessibility feature for screen readers, linking the accordion with other elements. It is used as the
+    /// panel's `id`, and the header's `id` appends `-header` to it. Defaults to an empty string, which generates unique ids.
+    #[prop_or_default]
+    pub aria_controls: AttrValue,
+
+    /// Shared classes and styles for the accordion, its lists and items.
+    ///
+    /// The instance's own class and style props override the theme's values when they aren't
+    /// empty. Defaults to the parent accordion's theme, then the `AccordionConfigProvider`'s, if any.
     #[prop_or_default]
-    pub aria_controls: &'static str,
+    pub theme: Option<Theme>,
 
     /// Custom inline styles for the container.
     ///
     /// Allows for custom styling of the accordion container. Defaults to an empty string.
     #[prop_or_default]
-    pub style: &'static str,
+    pub style: AttrValue,
 
     /// Custom inline styles for the expanded element.
     ///
     /// Allows for custom styling of the expanded element. Defaults to an empty string.
     #[prop_or_default]
-    pub expanded_style: &'static str,
+    pub expanded_style: AttrValue,
 
     /// Custom inline styles for the collapsed element.
     ///
     /// Allows for custom styling of the collapsed element. Defaults to an empty string.
     #[prop_or_default]
-    pub collapsed_style: &'static str,
+    pub collapsed_style: AttrValue,
+
+    /// Custom inline styles for the header while disabled.
+    ///
+    /// Appended to the expanded or collapsed styles when `disabled` is `true`. Defaults to an empty string.
+    #[prop_or_default]
+    pub disabled_style: AttrValue,
 
     /// Custom inline styles for the content container.
     ///
     /// Allows for custom styling of the content section. Defaults to an empty string.
     #[prop_or_default]
-    pub content_style: &'static str,
+    pub content_style: AttrValue,
 
     /// Custom class for the container.
     ///
     /// Applies a custom CSS class to the accordion container. Defaults to an empty string.
     #[prop_or_default]
-    pub class: &'static str,
+    pub class: AttrValue,
 
     /// Custom class for the expanded element.
     ///
     /// Applies a custom CSS class to the expanded content. Defaults to an empty string.
     #[prop_or_default]
-    pub expanded_class: &'static str,
+    pub expanded_class: AttrValue,
 
     /// Custom class for the collapsed element.
     ///
     /// Applies a custom CSS class to the collapsed content. Defaults to an empty string.
     #[prop_or_default]
-    pub collapsed_class: &'static str,
+    pub collapsed_class: AttrValue,
+
+    /// Custom class for the header while disabled.
+    ///
+    /// Added next to the expanded or collapsed class when `disabled` is `true`. Defaults to an empty string.
+    #[prop_or_default]
+    pub disabled_class: AttrValue,
 
     /// Custom class for the content container.
     ///
     /// Applies a custom CSS class to the content section. Defaults to an empty string.
     #[prop_or_default]
-    pub content_class: &'static str,
+    pub content_class: AttrValue,
 
     /// Whether ARIA attributes should be added to the HTML structure.
     ///
-    /// If `true`, ARIA attributes will be included for better accessibility. Defaults to `true`.
-    #[prop_or(true)]
-    pub aria_enabled: bool,
+    /// If `true`, ARIA attributes will be included for better accessibility. Defaults to the
+    /// `AccordionConfigProvider`'s value, or `true`.
+    #[prop_or_default]
+    pub aria_enabled: Option<bool>,
+
+    /// Level of the heading wrapping the header button.
+    ///
+    /// The header is rendered as `<h1>`–`<h6>`, with values outside `1..=6` clamped. Defaults to one level
+    /// below the parent accordion's heading, or for a top-level accordion the `AccordionConfigProvider`'s
+    /// level, or `3`.
+    #[prop_or_default]
+    pub heading_level: Option<u8>,
+
+    /// Indentation class for the container.
+    ///
+    /// Defaults to `accordion-depth-N`, where `N` is the nesting depth: `0` for a top-level accordion.
+    #[prop_or_default]
+    pub indent_class: Option<AttrValue>,
+
+    /// What happens to accordions nested in the panel when it closes.
+    ///
+    /// Defaults to `DescendantPolicy::Remember`, which keeps the state of uncontrolled nested accordions
+    /// even when the panel is unmounted.
+    #[prop_or_default]
+    pub descendants: DescendantPolicy,
 
     /// Duration of the animation in milliseconds.
     ///
-    /// Defines the animation speed for expanding or collapsing the accordion. Defaults to `600` milliseconds.
-    #[prop_or(600)]
-    pub duration: u64,
+    /// Defines the animation speed for expanding or collapsing the accordion. Defaults to the
+    /// `AccordionConfigProvider`'s duration, or `600` milliseconds.
+    #[prop_or_default]
+    pub duration: Option<u64>,
+
+    /// Whether the header ignores clicks.
+    ///
+    /// A disabled accordion keeps its current expand state and sets `aria-disabled`. Defaults to `false`.
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// Custom expand/collapse indicator shown in the header.
+    ///
+    /// Rotated along with the built-in one while the accordion is expanded. Defaults to `None`, which
+    /// renders a chevron.
+    #[prop_or_default]
+    pub indicator: Option<Html>,
+
+    /// Side of the header content on which the indicator is shown.
+    ///
+    /// Defaults to `Placement::Trailing`.
+    #[prop_or(Placement::Trailing)]
+    pub indicator_placement: Placement,
+
+    /// Whether the header shows no indicator.
+    ///
+    /// Defaults to `false`.
+    #[prop_or_default]
+    pub hide_indicator: bool,
+
+    /// Accessible label of the indicator.
+    ///
+    /// Defaults to an empty string, which labels it "Expand" or "Collapse" depending on the state.
+    #[prop_or_default]
+    pub indicator_label: AttrValue,
+
+    /// When the content container is present in the DOM.
+    ///
+    /// Closed panels that stay mounted are rendered `hidden` and `inert`. Defaults to `MountPolicy::UnmountOnClose`.
+    #[prop_or_default]
+    pub mount: MountPolicy,
+
+    /// Web storage key under which the expanded state is saved and restored.
+    ///
+    /// The saved state is restored after the first render, so server-rendered HTML always starts from
+    /// `default_expanded`. Nothing is persisted when storage is unavailable. Defaults to `None`.
+    #[prop_or_default]
+    pub persist_key: Option<AttrValue>,
+
+    /// Web storage used with `persist_key`.
+    ///
+    /// Defaults to `PersistStorage::Local`.
+    #[prop_or_default]
+    pub persist_storage: PersistStorage,
+
+    /// Whether the accordion opens when the page URL links to it.
+    ///
+    /// The accordion opens and scrolls its header into view when its id, `aria_controls` or the
+    /// generated one, is the URL's hash or is listed in the comma-separated `open` query parameter.
+    /// The hash is also followed when it changes. Defaults to `false`.
+    #[prop_or_default]
+    pub deep_link: bool,
+
+    /// How opening and closing the accordion from its header rewrites the page URL.
+    ///
+    /// Defaults to `UrlUpdate::None`.
+    #[prop_or_default]
+    pub update_url: UrlUpdate,
+
+    /// Async loader for additional panel content.
+    ///
+    /// Runs the first time the accordion opens, and its output is rendered after `children`. The result is kept
+    /// for later openings. Defaults to `None`.
+    #[prop_or_default]
+    pub loader: Option<PanelLoader>,
+
+    /// Placeholder shown while the `loader` is running.
+    ///
+    /// Used as the `Suspense` fallback around the loaded content. Defaults to an empty string.
+    #[prop_or_default]
+    pub loading: Html,
+
+    /// View rendered when the `loader` fails.
+    ///
+    /// Receives the error message and a `retry` callback. Defaults to a `role="alert"` message with a retry button.
+    #[prop_or_default]
+    pub error: Option<Callback<PanelError, Html>>,
 
     /// Callback executed before the accordion item is opened.
     ///
-    /// This callback is triggered before the accordion expands. Defaults to no-op.
+    /// This callback is triggered before the accordion expands. Calling `prevent_default` on the
+    /// `ToggleEvent` keeps the accordion closed. Defaults to no-op.
     #[prop_or_default]
-    pub will_open: Callback<()>,
+    pub will_open: Callback<ToggleEvent>,
 
     /// Callback executed when the accordion item is opened.
     ///
-    /// This callback is triggered after the accordion has expanded. Defaults to no-op.
+    /// This callback is triggered once the panel's transition has ended, or after `duration`
+    /// milliseconds if no transition runs. Defaults to no-op.
     #[prop_or_default]
     pub did_open: Callback<()>,
 
     /// Callback executed before the accordion item is closed.
     ///
-    /// This callback is triggered before the accordion collapses. Defaults to no-op.
+    /// This callback is triggered before the accordion collapses. Calling `prevent_default` on the
+    /// `ToggleEvent` keeps the accordion open. Defaults to no-op.
     #[prop_or_default]
-    pub will_close: Callback<()>,
+    pub will_close: Callback<ToggleEvent>,
 
     /// Callback executed when the accordion item is closed.
     ///
-    /// This callback is triggered after the accordion has collapsed. Defaults to no-op.
+    /// This callback is triggered once the panel's transition has ended, or after `duration`
+    /// milliseconds if no transition runs. Defaults to no-op.
     #[prop_or_default]
     pub did_close: Callback<()>,
+
+    /// Callback executed whenever the accordion enters a new lifecycle phase.
+    ///
+    /// Receives the new `Phase` (`Opening`, `Open`, `Closing` or `Closed`). Defaults to no-op.
+    #[prop_or_default]
+    pub on_phase_change: Callback<Phase>,
 }
 
 /// Accordion Component
@@ -136,26 +304,48 @@ pub struct AccordionProps {
 /// # Properties
 /// The component uses the `AccordionProps` struct for its properties. Key properties include:
 ///
-/// - **expand**: A state handle that manages the expansion state of the accordion (`UseStateHandle<bool>`).
+/// - **expand**: A state handle that manages the expansion state of the accordion (`Option<UseStateHandle<bool>>`). Default: `None`.
+/// - **handle**: A controller handle from `use_accordion`, driving the accordion through its callbacks (`Option<AccordionHandle>`). Default: `None`.
+/// - **default_expanded**: Whether an uncontrolled accordion starts expanded (`bool`). Default: `false`.
 /// - **expanded**: The content to display when the accordion is expanded (`Html`). Default: `""`.
 /// - **collapsed**: The content to display when the accordion is collapsed (`Html`). Default: `""`.
 /// - **children**: The child elements inside the accordion (`Html`). Default: `""`.
-/// - **size**: Defines the size of the accordion (`Size`). Default: `Size::XXLarge`.
-/// - **aria_controls**: The ARIA controls attribute for accessibility (`&'static str`). Default: `""`.
-/// - **style**: Custom inline styles for the accordion container (`String`). Default: `""`.
-/// - **expanded_style**: Custom inline styles for the expanded accordion section (`String`). Default: `""`.
-/// - **collapsed_style**: Custom inline styles for the collapsed accordion section (`String`). Default: `""`.
-/// - **content_style**: Custom inline styles for the content container (`String`). Default: `""`.
-/// - **class**: Custom CSS class for the accordion container (`String`). Default: `""`.
-/// - **expanded_class**: Custom CSS class for the expanded accordion section (`String`). Default: `""`.
-/// - **collapsed_class**: Custom CSS class for the collapsed accordion section (`String`). Default: `""`.
-/// - **content_class**: Custom CSS class for the content container (`String`). Default: `""`.
-/// - **aria_enabled**: Whether ARIA attributes should be added for accessibility (`bool`). Default: `true`.
-/// - **duration**: Duration of the animation when expanding or collapsing (`u64`). Default: `600`.
-/// - **will_open**: Callback triggered before the accordion expands (`Callback<()>`). Default: no-op.
+/// - **size**: Defines the size of the accordion (`Option<Size>`). Default: the provided size, or `Size::XXLarge`.
+/// - **aria_controls**: The ARIA controls attribute and panel id (`AttrValue`). Default: `""`, generating a unique id.
+/// - **theme**: Shared classes and styles, overridden by the props below (`Option<Theme>`). Default: the parent accordion's or provided theme.
+/// - **style**: Custom inline styles for the accordion container (`AttrValue`). Default: `""`.
+/// - **expanded_style**: Custom inline styles for the expanded accordion section (`AttrValue`). Default: `""`.
+/// - **collapsed_style**: Custom inline styles for the collapsed accordion section (`AttrValue`). Default: `""`.
+/// - **disabled_style**: Custom inline styles for the header while disabled (`AttrValue`). Default: `""`.
+/// - **content_style**: Custom inline styles for the content container (`AttrValue`). Default: `""`.
+/// - **class**: Custom CSS class for the accordion container (`AttrValue`). Default: `""`.
+/// - **expanded_class**: Custom CSS class for the expanded accordion section (`AttrValue`). Default: `""`.
+/// - **collapsed_class**: Custom CSS class for the collapsed accordion section (`AttrValue`). Default: `""`.
+/// - **disabled_class**: Custom CSS class for the header while disabled (`AttrValue`). Default: `""`.
+/// - **content_class**: Custom CSS class for the content container (`AttrValue`). Default: `""`.
+/// - **aria_enabled**: Whether ARIA attributes should be added for accessibility (`Option<bool>`). Default: the provided value, or `true`.
+/// - **heading_level**: Level of the heading wrapping the header button, from `1` to `6` (`Option<u8>`). Default: one below the parent accordion's, the provided level, or `3`.
+/// - **indent_class**: Indentation class for the container (`Option<AttrValue>`). Default: `accordion-depth-N` for nesting depth `N`.
+/// - **descendants**: Whether nested accordions are remembered or reset when the panel closes (`DescendantPolicy`). Default: `DescendantPolicy::Remember`.
+/// - **duration**: Duration of the animation when expanding or collapsing (`Option<u64>`). Default: the provided duration, or `600`.
+/// - **disabled**: Whether the header ignores clicks while keeping the current state (`bool`). Default: `false`.
+/// - **indicator**: Custom expand/collapse indicator, rotated with the state (`Option<Html>`). Default: a chevron.
+/// - **indicator_placement**: Side of the header content on which the indicator is shown (`Placement`). Default: `Placement::Trailing`.
+/// - **hide_indicator**: Whether the header shows no indicator (`bool`). Default: `false`.
+/// - **indicator_label**: Accessible label of the indicator (`AttrValue`). Default: `""`, labelling it "Expand" or "Collapse".
+/// - **mount**: When the content container is present in the DOM (`MountPolicy`). Default: `MountPolicy::UnmountOnClose`.
+/// - **persist_key**: Web storage key saving and restoring the expanded state (`Option<AttrValue>`). Default: `None`.
+/// - **persist_storage**: Web storage used with `persist_key` (`PersistStorage`). Default: `PersistStorage::Local`.
+/// - **deep_link**: Whether the accordion opens when the URL's hash or `open` query parameter names its id (`bool`). Default: `false`.
+/// - **update_url**: How toggling the accordion rewrites the page URL (`UrlUpdate`). Default: `UrlUpdate::None`.
+/// - **loader**: Async loader for panel content, run on first expand (`Option<PanelLoader>`). Default: `None`.
+/// - **loading**: Placeholder shown while the loader runs (`Html`). Default: `""`.
+/// - **error**: View rendered when the loader fails (`Option<Callback<PanelError, Html>>`). Default: alert with a retry button.
+/// - **will_open**: Callback triggered before the accordion expands; can cancel it (`Callback<ToggleEvent>`). Default: no-op.
 /// - **did_open**: Callback triggered after the accordion expands (`Callback<()>`). Default: no-op.
-/// - **will_close**: Callback triggered before the accordion collapses (`Callback<()>`). Default: no-op.
+/// - **will_close**: Callback triggered before the accordion collapses; can cancel it (`Callback<ToggleEvent>`). Default: no-op.
 /// - **did_close**: Callback triggered after the accordion collapses (`Callback<()>`). Default: no-op.
+/// - **on_phase_change**: Callback triggered when the lifecycle phase changes (`Callback<Phase>`). Default: no-op.
 ///
 /// # Features
 /// - Customizable expanded and collapsed content.
@@ -184,6 +374,23 @@ pub struct AccordionProps {
 /// }
 /// ```
 ///
+/// ## Uncontrolled Accordion
+/// ```rust
+/// use yew::prelude::*;
+/// use accordion_rs::yew::Accordion;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <Accordion
+///             default_expanded=true
+///             expanded="This is expanded content"
+///             collapsed="This is collapsed content"
+///         />
+///     }
+/// }
+/// ```
+///
 /// ## Accordion with Custom Styles
 /// ```rust
 /// use yew::prelude::*;
@@ -234,82 +441,605 @@ pub struct AccordionProps {
 /// }
 /// ```
 ///
+/// ## Cancelable Close
+/// ```rust
+/// use yew::prelude::*;
+/// use accordion_rs::yew::Accordion;
+/// use accordion_rs::ToggleEvent;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let has_unsaved_edits = true;
+///     let will_close = Callback::from(move |event: ToggleEvent| {
+///         if has_unsaved_edits {
+///             event.prevent_default();
+///         }
+///     });
+///
+///     html! {
+///         <Accordion
+///             default_expanded=true
+///             expanded="Editing"
+///             collapsed="Edit"
+///             will_close={will_close}
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Accordion with Async Content
+/// ```rust
+/// use yew::prelude::*;
+/// use accordion_rs::yew::{Accordion, PanelLoader};
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let loader = use_memo((), |_| {
+///         PanelLoader::new(|| async { Ok(html! { <p>{ "Loaded on first open" }</p> }) })
+///     });
+///
+///     html! {
+///         <Accordion
+///             expanded="Details"
+///             collapsed="Details"
+///             loader={(*loader).clone()}
+///             loading={html! { <p>{ "Loading..." }</p> }}
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Deep-Linked Accordion
+/// ```rust
+/// use yew::prelude::*;
+/// use accordion_rs::yew::Accordion;
+/// use accordion_rs::UrlUpdate;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     // Opened by `/faq#faq-shipping` or `/faq?open=faq-shipping`.
+///     html! {
+///         <Accordion
+///             aria_controls="faq-shipping"
+///             deep_link=true
+///             update_url={UrlUpdate::Hash}
+///             expanded="Shipping takes two days."
+///             collapsed="How long does shipping take?"
+///         />
+///     }
+/// }
+/// ```
+///
+/// ## Nested Accordions
+/// ```rust
+/// use yew::prelude::*;
+/// use accordion_rs::yew::{Accordion, Item, List};
+/// use accordion_rs::DescendantPolicy;
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <Accordion expanded="Products" collapsed="Products" descendants={DescendantPolicy::Reset}>
+///             <List>
+///                 <Item>
+///                     // Rendered as an `<h4>`, with the `accordion-depth-1` class.
+///                     <Accordion expanded="Hardware" collapsed="Hardware">
+///                         <List>
+///                             <Item>{ "Keyboards, mice and monitors." }</Item>
+///                         </List>
+///                     </Accordion>
+///                 </Item>
+///             </List>
+///         </Accordion>
+///     }
+/// }
+/// ```
+///
 /// # Behavior
 /// - The component uses a state to track whether the accordion is expanded or collapsed.
 /// - Clicking the accordion header toggles between expanded and collapsed states, with smooth animation transitions.
 /// - It emits callbacks when the accordion is about to open or close, and after those actions have completed.
+/// - The lifecycle runs `Closed` → `Opening` → `Open` → `Closing` → `Closed`. `did_open` and `did_close` fire
+///   on the panel's `transitionend`, or after `duration` milliseconds as a fallback.
+/// - The current phase is reported through `on_phase_change` and the container's `data-phase` attribute.
+/// - The panel's height is measured and animated between `0` and its content height over `duration` milliseconds.
+/// - The `mount` policy decides whether a closed panel is unmounted or kept `hidden` and `inert`, preserving its state.
+/// - A `loader` runs on first expand inside a `Suspense` boundary showing `loading`; failures render `error`,
+///   which can retry the load.
 /// - ARIA attributes are dynamically added for accessibility when `aria_enabled` is set to `true`.
+/// - The header is a `<button>` inside an `<h1>`–`<h6>` heading chosen by `heading_level`, following the
+///   WAI-ARIA accordion pattern.
+/// - Headers follow the WAI-ARIA accordion keyboard pattern: `Enter`/`Space` toggles,
+///   `ArrowUp`/`ArrowDown` move focus to the previous/next sibling accordion header (wrapping), and
+///   `Home`/`End` move it to the first/last one.
+/// - The header and panel get ids, generated from a counter when `aria_controls` is empty. The header's
+///   `aria-controls` points at the panel, which has `role="region"` and `aria-labelledby` pointing back.
+///   Call `reset_ids` before each server render to keep generated ids stable through hydration.
+/// - Accordions nested in a panel read the parent's `AccordionParent` context. Their depth defaults the heading
+///   level, indentation class and generated ids, and they join an `AccordionGroup` only at their own depth.
+///   Uncontrolled nested accordions keep their state while the parent is closed, or go back to
+///   `default_expanded` with `DescendantPolicy::Reset`.
+/// - Without `expand`, the accordion is uncontrolled: it owns its state, starting from `default_expanded`.
+/// - With a `persist_key`, the expanded state is saved to web storage and restored after the first render.
+///   Without storage, for instance during SSR, the accordion behaves as if no key were given.
+/// - With `deep_link`, an accordion linked by the URL's hash or `open` query parameter opens like a header click and scrolls into view;
+///   `update_url` keeps the URL in sync through `history.replaceState`, leaving the path and the history state untouched.
+/// - Inside an `AccordionGroup`, the group owns the expansion state and `expand` is ignored.
+/// - A `disabled` accordion ignores header clicks and keeps its expand state; the header gets `aria-disabled`
+///   and `disabled_class`/`disabled_style`.
 ///
 /// # Notes
 /// - The `aria_enabled` property can be set to `false` to disable ARIA attributes for cases where they are not needed.
 /// - The `will_open`, `did_open`, `will_close`, and `did_close` callbacks can be used to hook into the accordion's state changes for custom behavior.
+/// - `will_open` and `will_close` receive a `ToggleEvent`; calling `prevent_default` on it cancels the toggle.
 /// - The `size` property allows customization of the accordion's size (e.g., `Size::Small`, `Size::Medium`, `Size::Large`).
 #[function_component]
 pub fn Accordion(props: &AccordionProps) -> Html {
-    let is_expanded = &props.expand;
-    let is_expanded_value = **is_expanded;
+    let parent = use_context::<AccordionParent>();
+    let config = use_context::<AccordionConfig>().unwrap_or_default();
+    let duration = config.duration(props.duration);
+    let aria_enabled = config.aria_enabled(props.aria_enabled);
+    let depth = parent.as_ref().map_or(0, |parent| parent.depth + 1);
+    let group = use_context::<AccordionGroupContext>().filter(|group| group.depth == depth);
+    let group_key = use_state(|| group.as_ref().map(AccordionGroupContext::register));
+    let nested_index = use_state(|| {
+        parent
+            .as_ref()
+            .map(|parent| parent.nested.borrow_mut().register())
+    });
+    let own_state = use_state(|| match (&parent, *nested_index) {
+        (Some(parent), Some(index)) => parent
+            .nested
+            .borrow()
+            .remembered(index)
+            .unwrap_or(props.default_expanded),
+        _ => props.default_expanded,
+    });
+    let generated_id = use_state(|| {
+        AccordionIds::generate(
+            parent
+                .as_ref()
+                .map(|parent| parent.id.as_str())
+                .zip(*nested_index),
+        )
+    });
+    let ids = AccordionIds::new(&props.aria_controls, &generated_id);
+    if let (Some(group), Some(key)) = (&group, *group_key) {
+        group.members.borrow_mut().set_id(key, &ids.base);
+    }
+    {
+        let membership = group.clone().zip(*group_key);
+        use_effect_with((), move |_| {
+            move || {
+                if let Some((group, key)) = membership {
+                    group.unregister(key);
+                }
+            }
+        });
+    }
+    let heading_level = config.heading_level(
+        props.heading_level,
+        parent.as_ref().map(|parent| parent.heading_level),
+    );
+    let is_expanded = props
+        .handle
+        .as_ref()
+        .map(|handle| handle.expanded.clone())
+        .or_else(|| props.expand.clone())
+        .unwrap_or(own_state.clone());
+
+    {
+        let group = group.clone();
+        let group_key = *group_key;
+        let default_expanded = props.default_expanded;
+        use_effect_with((), move |_| {
+            if let (Some(group), Some(key), true) = (group, group_key, default_expanded) {
+                group.set_open(key, true);
+            }
+        });
+    }
+
+    let is_expanded_value = match (&group, *group_key) {
+        (Some(group), Some(key)) => group.is_open(key),
+        _ => *is_expanded,
+    };
+    let set_expanded = {
+        let group = group.clone();
+        let group_key = *group_key;
+        Callback::from(move |value: bool| match (&group, group_key) {
+            (Some(group), Some(key)) => group.set_open(key, value),
+            _ => is_expanded.set(value),
+        })
+    };
+
+    {
+        let parent = parent.clone();
+        let nested_index = *nested_index;
+        use_effect_with(*own_state, move |expanded| {
+            if let (Some(parent), Some(index)) = (parent, nested_index) {
+                parent.nested.borrow_mut().remember(index, *expanded);
+            }
+        });
+    }
+
+    {
+        let parent_resets = parent.as_ref().map_or(0, |parent| parent.resets);
+        let seen_resets = use_mut_ref(|| parent_resets);
+        let set_expanded = set_expanded.clone();
+        let default_expanded = props.default_expanded;
+        use_effect_with(parent_resets, move |resets| {
+            if seen_resets.replace(*resets) != *resets {
+                set_expanded.emit(default_expanded);
+            }
+        });
+    }
+
+    {
+        let restored = use_mut_ref(|| false);
+        let set_expanded = set_expanded.clone();
+        let persist_key = props.persist_key.clone();
+        let storage = props.persist_storage;
+        use_effect_with(is_expanded_value, move |expanded| {
+            let Some(key) = persist_key else {
+                return;
+            };
+            if !restored.replace(true) {
+                if let Some(saved) = storage.load(&key).and_then(|saved| saved.parse().ok()) {
+                    if saved != *expanded {
+                        set_expanded.emit(saved);
+                    }
+                    return;
+                }
+            }
+            storage.save(&key, &expanded.to_string());
+        });
+    }
+
+    let link_requests = use_state(|| 0_usize);
+    {
+        let link_requests = link_requests.clone();
+        let sent = use_mut_ref(|| 0_usize);
+        let ids = ids.clone();
+        let deep_link = props.deep_link;
+        use_effect_with((), move |_| {
+            let listener = deep_link.then(|| {
+                let open_linked = move || {
+                    if ids.is_linked(&location_links()) {
+                        // The listener outlives this render, so the open runs from an effect.
+                        *sent.borrow_mut() += 1;
+                        link_requests.set(*sent.borrow());
+                        scroll_into_view(&ids.header);
+                    }
+                };
+                open_linked();
+                let listener = Closure::<dyn Fn()>::new(open_linked);
+                if let Some(window) = web_sys::window() {
+                    let _ = window.add_event_listener_with_callback(
+                        "hashchange",
+                        listener.as_ref().unchecked_ref(),
+                    );
+                }
+                listener
+            });
+            move || {
+                if let (Some(listener), Some(window)) = (listener, web_sys::window()) {
+                    let _ = window.remove_event_listener_with_callback(
+                        "hashchange",
+                        listener.as_ref().unchecked_ref(),
+                    );
+                }
+            }
+        });
+    }
+
+    let phase = use_state(|| Phase::at_rest(is_expanded_value));
+    let fallback = use_mut_ref(|| None::<Timeout>);
+    let phase_value = if phase.is_expanded() == is_expanded_value {
+        *phase
+    } else {
+        Phase::at_rest(is_expanded_value)
+    };
+
+    let opened_before = use_mut_ref(|| false);
+    if phase_value != Phase::Closed {
+        *opened_before.borrow_mut() = true;
+    }
+    let is_mounted = props.mount.is_mounted(phase_value, *opened_before.borrow());
+    let is_hidden = phase_value == Phase::Closed;
+
+    let nested = use_mut_ref(NestedState::default);
+    if !is_mounted {
+        nested.borrow_mut().restart();
+    }
+    let own_resets = use_state(|| 0_usize);
+    {
+        let nested = nested.clone();
+        let own_resets = own_resets.clone();
+        let was_closed = use_mut_ref(|| is_hidden);
+        let descendants = props.descendants;
+        use_effect_with(is_hidden, move |closed| {
+            let was_closed = was_closed.replace(*closed);
+            if *closed && !was_closed && descendants == DescendantPolicy::Reset {
+                nested.borrow_mut().forget();
+                own_resets.set(*own_resets + 1);
+            }
+        });
+    }
+    let themed = props
+        .theme
+        .clone()
+        .or_else(|| parent.as_ref().and_then(AccordionParent::theme))
+        .or_else(|| config.theme.clone());
+    let theme = themed.clone().unwrap_or_default();
+    let context = AccordionParent {
+        depth,
+        id: ids.base.clone(),
+        heading_level,
+        theme: themed,
+        resets: parent.as_ref().map_or(0, |parent| parent.resets) + *own_resets,
+        nested,
+    };
+
+    let load_cache = use_memo((), |_| LoadCache::default());
+    let load_generation = use_state(|| 0_usize);
+    let retry_load = {
+        let load_cache = load_cache.clone();
+        let load_generation = load_generation.clone();
+        Callback::from(move |_| {
+            *load_cache.0.borrow_mut() = LoadState::Idle;
+            load_generation.set(*load_generation + 1);
+        })
+    };
+
+    let panel = use_node_ref();
+    let measured_height = use_state(|| None::<f64>);
+    {
+        let panel = panel.clone();
+        let measured_height = measured_height.clone();
+        use_effect_with((phase_value, *measured_height), move |(phase, height)| {
+            let measure = || {
+                panel
+                    .cast::<Element>()
+                    .map(|panel| panel.scroll_height() as f64)
+            };
+            match (phase, height) {
+                (Phase::Opening | Phase::Closing, None) => measured_height.set(measure()),
+                (Phase::Closing, Some(height)) if *height > 0.0 => {
+                    // Reading the layout commits the measured height before collapsing to zero.
+                    measure();
+                    measured_height.set(Some(0.0));
+                }
+                _ => {}
+            }
+        });
+    }
+
+    let finish_transition = {
+        let phase = phase.clone();
+        let props = props.clone();
+
+        Callback::from(move |settled: Phase| {
+            phase.set(settled);
+            props.on_phase_change.emit(settled);
+            if settled == Phase::Open {
+                props.did_open.emit(());
+            } else {
+                props.did_close.emit(());
+            }
+        })
+    };
 
     let toggle_expansion = {
-        let is_expanded = is_expanded.clone();
         let props = props.clone();
+        let fallback = fallback.clone();
+        let measured_height = measured_height.clone();
+        let finish_transition = finish_transition.clone();
+        let id = ids.base.clone();
 
-        move |e: MouseEvent| {
-            e.prevent_default();
+        Callback::from(move |_: ()| {
+            if props.disabled {
+                return;
+            }
 
+            let event = ToggleEvent::new(!is_expanded_value);
             if is_expanded_value {
-                props.will_close.emit(());
-                is_expanded.set(false);
-                props.did_close.emit(());
+                props.will_close.emit(event.clone());
             } else {
-                props.will_open.emit(());
-                is_expanded.set(true);
-                props.did_open.emit(());
+                props.will_open.emit(event.clone());
             }
+            if event.default_prevented() {
+                return;
+            }
+
+            set_expanded.emit(!is_expanded_value);
+            props.update_url.update_location(&id, !is_expanded_value);
+            let next = if is_expanded_value {
+                Phase::Closing
+            } else {
+                Phase::Opening
+            };
+            phase.set(next);
+            measured_height.set(None);
+            props.on_phase_change.emit(next);
+
+            let finish_transition = finish_transition.clone();
+            *fallback.borrow_mut() = Some(Timeout::new(duration as u32, move || {
+                finish_transition.emit(next.settled())
+            }));
+        })
+    };
+
+    {
+        let handle = props.handle.clone();
+        use_effect_with(phase_value, move |phase| {
+            if let Some(handle) = handle {
+                handle.phase.set(*phase);
+            }
+        });
+    }
+
+    {
+        let request = props.handle.as_ref().map(|handle| *handle.request);
+        let seen = use_mut_ref(|| request.unwrap_or_default().seq);
+        let toggle_expansion = toggle_expansion.clone();
+        use_effect_with(request, move |request| {
+            if let Some(request) = request {
+                if seen.replace(request.seq) != request.seq
+                    && request.action.applies(is_expanded_value)
+                {
+                    toggle_expansion.emit(());
+                }
+            }
+        });
+    }
+
+    {
+        let toggle_expansion = toggle_expansion.clone();
+        use_effect_with(*link_requests, move |requests| {
+            if *requests > 0 && ControlAction::Open.applies(is_expanded_value) {
+                toggle_expansion.emit(());
+            }
+        });
+    }
+
+    let on_header_click = move |e: MouseEvent| {
+        e.prevent_default();
+        toggle_expansion.emit(());
+    };
+
+    let on_header_keydown = move |e: KeyboardEvent| {
+        if e.target() != e.current_target() {
+            return;
+        }
+        let Some(key) = HeaderKey::from_key(&e.key()) else {
+            return;
+        };
+        e.prevent_default();
+        if let Some(header) = e.target_dyn_into::<Element>() {
+            focus_sibling_header(&header, key);
+        }
+    };
+
+    let on_transition_end = move |e: TransitionEvent| {
+        if phase_value.is_transitioning()
+            && e.target() == e.current_target()
+            && e.property_name() == "height"
+        {
+            fallback.borrow_mut().take();
+            finish_transition.emit(phase_value.settled());
+        }
+    };
+
+    let size_rules = config.size_rules(props.size.as_ref(), &ids.base);
+    let indicator = (!props.hide_indicator).then(|| {
+        html! {
+            <span
+                class={INDICATOR_CLASS}
+                role={aria_enabled.then_some("img")}
+                aria-label={aria_enabled.then(|| indicator_label(&props.indicator_label, is_expanded_value).to_string())}
+                data-placement={props.indicator_placement.as_str()}
+                data-expanded={is_expanded_value.then_some("")}
+                style={indicator_style(props.indicator_placement, is_expanded_value)}
+            >
+                { props.indicator.clone().unwrap_or_else(chevron) }
+            </span>
         }
+    });
+    let (leading_indicator, trailing_indicator) = match props.indicator_placement {
+        Placement::Leading => (indicator, None),
+        Placement::Trailing => (None, indicator),
     };
+    let size_id = size_rules.is_some().then(|| ids.base.clone());
 
     html! {
         <div
             style={format!(
-                "{} {}",
-                props.size.to_style(),
-                props.style
+                "{ROOT_STYLE} {}{}",
+                config.properties(props.duration, props.size.as_ref(), depth > 0),
+                Theme::pick(&props.style, &theme.style)
+            )}
+            class={classes!(
+                ROOT_CLASS,
+                Theme::pick(&props.class, &theme.class).to_string(),
+                props.indent_class.as_ref().map_or_else(|| depth_class(depth), ToString::to_string)
             )}
-            class={props.class}
+            data-phase={phase_value.as_str()}
+            data-accordion-id={size_id}
         >
-            <div
-                aria-expanded={if props.aria_enabled { Some(is_expanded_value.to_string()) } else { None }}
-                aria-controls={if props.aria_enabled { Some(props.aria_controls) } else { None }}
-                onclick={toggle_expansion.clone()}
-                class={if is_expanded_value {
-                        props.expanded_class
-                    } else {
-                        props.collapsed_class
-                    }}
-                style={format!(
-                    "cursor: pointer; transition: all {}ms; {}",
-                    props.duration,
-                    if is_expanded_value {
-                        props.expanded_style
-                    } else {
-                        props.collapsed_style
-                    }
-                )}
-            >
-                { if is_expanded_value { props.expanded.clone() } else { props.collapsed.clone() } }
-            </div>
-            { if is_expanded_value {
+            { for size_rules.map(|rules| html! { <style>{ rules }</style> }) }
+            <@{format!("h{heading_level}")} class={HEADING_CLASS} style={HEADING_STYLE}>
+                <button
+                    type="button"
+                    id={ids.header.clone()}
+                    aria-expanded={if aria_enabled { Some(is_expanded_value.to_string()) } else { None }}
+                    aria-controls={if aria_enabled { Some(ids.panel.clone()) } else { None }}
+                    aria-disabled={(aria_enabled && props.disabled).then_some("true")}
+                    data-accordion-header=""
+                    data-disabled={props.disabled.then_some("")}
+                    onclick={on_header_click}
+                    onkeydown={on_header_keydown}
+                    class={classes!(
+                        HEADER_CLASS,
+                        theme.header_class.clone(),
+                        if is_expanded_value {
+                            Theme::pick(&props.expanded_class, &theme.expanded_class)
+                        } else {
+                            Theme::pick(&props.collapsed_class, &theme.collapsed_class)
+                        }
+                        .to_string(),
+                        props.disabled.then(|| props.disabled_class.clone())
+                    )}
+                    style={format!(
+                        "{HEADER_STYLE} cursor: {}; {} {} {}",
+                        if props.disabled { "not-allowed" } else { "pointer" },
+                        theme.header_style,
+                        if is_expanded_value {
+                            Theme::pick(&props.expanded_style, &theme.expanded_style)
+                        } else {
+                            Theme::pick(&props.collapsed_style, &theme.collapsed_style)
+                        },
+                        if props.disabled { props.disabled_style.as_str() } else { "" }
+                    )}
+                >
+                    { for leading_indicator }
+                    { if is_expanded_value { props.expanded.clone() } else { props.collapsed.clone() } }
+                    { for trailing_indicator }
+                </button>
+            </@>
+            { if is_mounted {
                 html! {
                     <div
-                        id={props.aria_controls}
-                        class={props.content_class}
+                        ref={panel}
+                        id={ids.panel}
+                        role={aria_enabled.then_some("region")}
+                        aria-labelledby={if aria_enabled { Some(ids.header) } else { None }}
+                        class={classes!(PANEL_CLASS, Theme::pick(&props.content_class, &theme.content_class).to_string())}
+                        hidden={is_hidden}
+                        inert={is_hidden.then_some("")}
+                        ontransitionend={on_transition_end}
                         style={format!(
-                            "overflow: hidden; transition: all {}ms; {}",
-                            props.duration,
-                            props.content_style
+                            "{PANEL_STYLE} {} {}",
+                            panel_height(phase_value, *measured_height),
+                            Theme::pick(&props.content_style, &theme.content_style)
                         )}
                     >
+                    <ContextProvider<AccordionParent> {context}>
                     { for props.children.iter() }
+                    { match &props.loader {
+                        Some(loader) if *opened_before.borrow() => html! {
+                            <Suspense fallback={props.loading.clone()}>
+                                <LoadedPanel
+                                    loader={loader.clone()}
+                                    cache={(*load_cache).clone()}
+                                    generation={*load_generation}
+                                    error={props.error.clone().unwrap_or_else(default_error_view)}
+                                    retry={retry_load}
+                                />
+                            </Suspense>
+                        },
+                        _ => html! {},
+                    } }
+                    </ContextProvider<AccordionParent>>
                     </div>
                 }
             } else {
@@ -319,6 +1049,576 @@ pub fn Accordion(props: &AccordionProps) -> Html {
     }
 }
 
+/// Controller handle of an `Accordion`, created by `use_accordion`.
+///
+/// Passed to an accordion's `handle` prop, it lets code outside the accordion open and close it
+/// the way a header click does: `will_open`/`will_close` can cancel the change, the panel animates,
+/// and `did_open`/`did_close` fire once it settles.
+#[derive(Clone, PartialEq)]
+pub struct AccordionHandle {
+    expanded: UseStateHandle<bool>,
+    phase: UseStateHandle<Phase>,
+    request: UseReducerHandle<ControlRequest>,
+}
+
+impl AccordionHandle {
+    /// Opens the accordion, unless it is already open.
+    pub fn open(&self) {
+        self.request.dispatch(ControlAction::Open);
+    }
+
+    /// Closes the accordion, unless it is already closed.
+    pub fn close(&self) {
+        self.request.dispatch(ControlAction::Close);
+    }
+
+    /// Opens a closed accordion, or closes an open one.
+    pub fn toggle(&self) {
+        self.request.dispatch(ControlAction::Toggle);
+    }
+
+    /// Whether the accordion is expanded.
+    pub fn is_open(&self) -> bool {
+        *self.expanded
+    }
+
+    /// Current lifecycle phase of the accordion.
+    pub fn phase(&self) -> Phase {
+        *self.phase
+    }
+}
+
+impl Reducible for ControlRequest {
+    type Action = ControlAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        Rc::new(self.next(action))
+    }
+}
+
+/// Creates a controller handle for an `Accordion`, starting expanded if `default_expanded` is set.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use accordion_rs::yew::{use_accordion, Accordion};
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     let handle = use_accordion(false);
+///     let open = {
+///         let handle = handle.clone();
+///         Callback::from(move |_: MouseEvent| handle.open())
+///     };
+///
+///     html! {
+///         <>
+///             <a href="#details" onclick={open}>{ "Show details" }</a>
+///             <Accordion
+///                 handle={handle.clone()}
+///                 expanded="Details (open)"
+///                 collapsed="Details"
+///             />
+///         </>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_accordion(default_expanded: bool) -> AccordionHandle {
+    let expanded = use_state_eq(|| default_expanded);
+    let phase = use_state_eq(|| Phase::at_rest(default_expanded));
+    let request = use_reducer(ControlRequest::default);
+
+    AccordionHandle {
+        expanded,
+        phase,
+        request,
+    }
+}
+
+/// Moves focus from `header` to one of its sibling accordion headers.
+fn focus_sibling_header(header: &Element, key: HeaderKey) {
+    let Some(Ok(nodes)) = header
+        .parent_element()
+        .and_then(|heading| heading.parent_element())
+        .and_then(|accordion| accordion.parent_element())
+        .map(|parent| parent.query_selector_all(SIBLING_HEADERS))
+    else {
+        return;
+    };
+    let headers: Vec<HtmlElement> = (0..nodes.length())
+        .filter_map(|index| nodes.get(index)?.dyn_into().ok())
+        .collect();
+    let target = headers
+        .iter()
+        .position(|node| node.is_same_node(Some(header)))
+        .and_then(|current| key.focus_index(current, headers.len()));
+    if let Some(target) = target {
+        let _ = headers[target].focus();
+    }
+}
+
+/// Future returned by a `PanelLoader`.
+pub type PanelFuture = Pin<Box<dyn Future<Output = Result<Html, String>>>>;
+
+/// Async loader for the content of an accordion's panel.
+///
+/// The loader runs the first time the accordion opens. Its output is kept, so closing and
+/// reopening the accordion doesn't load the content again.
+#[derive(Clone)]
+pub struct PanelLoader(Rc<dyn Fn() -> PanelFuture>);
+
+impl PanelLoader {
+    /// Creates a loader from an async function producing the panel content or an error message.
+    pub fn new<F, Fut>(load: F) -> Self
+    where
+        F: Fn() -> Fut + 'static,
+        Fut: Future<Output = Result<Html, String>> + 'static,
+    {
+        Self(Rc::new(move || Box::pin(load())))
+    }
+
+    fn load(&self) -> PanelFuture {
+        (self.0)()
+    }
+}
+
+impl PartialEq for PanelLoader {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A failed `PanelLoader` run, passed to the accordion's `error` view.
+#[derive(Clone, PartialEq)]
+pub struct PanelError {
+    /// The error message returned by the loader.
+    pub message: String,
+
+    /// Clears the error and runs the loader again.
+    pub retry: Callback<()>,
+}
+
+/// Built-in indicator, a chevron pointing down that turns up while expanded.
+fn chevron() -> Html {
+    html! {
+        <svg
+            viewBox="0 0 24 24"
+            width="1em"
+            height="1em"
+            fill="none"
+            stroke="currentColor"
+            stroke-width="2"
+            stroke-linecap="round"
+            stroke-linejoin="round"
+            aria-hidden="true"
+        >
+            <path d="M6 9l6 6 6-6" />
+        </svg>
+    }
+}
+
+fn default_error_view() -> Callback<PanelError, Html> {
+    Callback::from(|error: PanelError| {
+        let retry = error.retry.reform(|_: MouseEvent| ());
+        html! {
+            <div role="alert">
+                { error.message }
+                <button type="button" onclick={retry}>{ "Retry" }</button>
+            </div>
+        }
+    })
+}
+
+#[derive(Default)]
+enum LoadState {
+    #[default]
+    Idle,
+    Loading(Suspension),
+    Loaded(Result<Html, String>),
+}
+
+/// Loader output shared between an accordion and its `LoadedPanel`, surviving remounts.
+#[derive(Clone, Default)]
+struct LoadCache(Rc<RefCell<LoadState>>);
+
+impl PartialEq for LoadCache {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[derive(Properties, Clone, PartialEq)]
+struct LoadedPanelProps {
+    loader: PanelLoader,
+    cache: LoadCache,
+    generation: usize,
+    error: Callback<PanelError, Html>,
+    retry: Callback<()>,
+}
+
+/// Renders the loader output, suspending the surrounding `Suspense` until it is ready.
+#[function_component]
+fn LoadedPanel(props: &LoadedPanelProps) -> HtmlResult {
+    if matches!(*props.cache.0.borrow(), LoadState::Idle) {
+        let cache = props.cache.clone();
+        let load = props.loader.load();
+        let suspension = Suspension::from_future(async move {
+            let result = load.await;
+            *cache.0.borrow_mut() = LoadState::Loaded(result);
+        });
+        let mut state = props.cache.0.borrow_mut();
+        if matches!(*state, LoadState::Idle) {
+            *state = LoadState::Loading(suspension);
+        }
+    }
+
+    match &*props.cache.0.borrow() {
+        LoadState::Loaded(Ok(content)) => Ok(content.clone()),
+        LoadState::Loaded(Err(message)) => Ok(props.error.emit(PanelError {
+            message: message.clone(),
+            retry: props.retry.clone(),
+        })),
+        LoadState::Loading(suspension) => Err(suspension.clone().into()),
+        LoadState::Idle => Ok(Html::default()),
+    }
+}
+
+/// Context provided by an accordion to the content of its panel.
+///
+/// Nested accordions read it to find their depth, default their heading level, indentation
+/// class and ids, and remember or reset their state when the parent closes.
+#[derive(Clone, Debug)]
+pub struct AccordionParent {
+    depth: usize,
+    id: String,
+    heading_level: u8,
+    theme: Option<Theme>,
+    resets: usize,
+    nested: Rc<RefCell<NestedState>>,
+}
+
+impl PartialEq for AccordionParent {
+    fn eq(&self, other: &Self) -> bool {
+        self.depth == other.depth
+            && self.id == other.id
+            && self.heading_level == other.heading_level
+            && self.theme == other.theme
+            && self.resets == other.resets
+            && Rc::ptr_eq(&self.nested, &other.nested)
+    }
+}
+
+impl AccordionParent {
+    /// Nesting depth of the parent accordion: `0` for a top-level accordion.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Base id of the parent accordion, prefixing the ids generated for nested accordions.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Heading level of the parent accordion's header.
+    pub fn heading_level(&self) -> u8 {
+        self.heading_level
+    }
+
+    /// Theme of the parent accordion, inherited by the lists, items and accordions inside it.
+    pub fn theme(&self) -> Option<Theme> {
+        self.theme.clone()
+    }
+}
+
+/// Context shared by an `AccordionGroup` with the accordions rendered inside it.
+///
+/// `expand_all`, `collapse_all` and `toggle` change the group's state directly: they skip the
+/// accordions' `will_open`/`will_close` and `did_open`/`did_close` callbacks, and the panels
+/// switch without animating. So does an accordion that `single` mode closes when another opens.
+#[derive(Clone, PartialEq)]
+pub struct AccordionGroupContext {
+    state: UseReducerHandle<GroupState>,
+    depth: usize,
+    single: bool,
+    members: Rc<RefCell<GroupMembers>>,
+}
+
+impl AccordionGroupContext {
+    /// Hands out the key of the next accordion joining the group.
+    fn register(&self) -> usize {
+        self.members.borrow_mut().register()
+    }
+
+    /// Removes the accordion registered under `key`, closing it so it no longer counts as open.
+    fn unregister(&self, key: usize) {
+        self.members.borrow_mut().unregister(key);
+        self.set_open(key, false);
+    }
+
+    /// Whether the accordion registered under `key` is open.
+    pub fn is_open(&self, key: usize) -> bool {
+        self.state.is_open(key)
+    }
+
+    /// Opens or closes the accordion registered under `key`, honoring `single` mode.
+    pub fn set_open(&self, key: usize, open: bool) {
+        self.state.dispatch(GroupAction::SetOpen {
+            key,
+            open,
+            single: self.single,
+        });
+    }
+
+    /// Opens every accordion of the group, or only the first one in `single` mode.
+    pub fn expand_all(&self) {
+        self.set_all(true);
+    }
+
+    /// Closes every accordion of the group.
+    pub fn collapse_all(&self) {
+        self.set_all(false);
+    }
+
+    fn set_all(&self, open: bool) {
+        self.state.dispatch(GroupAction::SetAll {
+            keys: self.members.borrow().keys(),
+            open,
+            single: self.single,
+        });
+    }
+
+    /// Opens or closes the accordion whose id, `aria_controls` or the generated one, is `id`.
+    ///
+    /// Ids that don't belong to the group are ignored.
+    pub fn toggle(&self, id: &str) {
+        let key = self.members.borrow().key(id);
+        if let Some(key) = key {
+            self.set_open(key, !self.is_open(key));
+        }
+    }
+
+    /// Whether all, none or some of the group's accordions are open.
+    pub fn status(&self) -> GroupStatus {
+        self.state.status(&self.members.borrow().keys())
+    }
+}
+
+/// Updates dispatched to the state of an `AccordionGroup`.
+pub(crate) enum GroupAction {
+    SetOpen {
+        key: usize,
+        open: bool,
+        single: bool,
+    },
+    SetAll {
+        keys: Vec<usize>,
+        open: bool,
+        single: bool,
+    },
+    Restore(GroupState),
+}
+
+impl Reducible for GroupState {
+    type Action = GroupAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut state = (*self).clone();
+        match action {
+            GroupAction::SetOpen { key, open, single } => state.set_open(key, open, single),
+            GroupAction::SetAll { keys, open, single } => state.set_all(&keys, open, single),
+            GroupAction::Restore(restored) => state = restored,
+        }
+        Rc::new(state)
+    }
+}
+
+/// Properties for the AccordionGroup component.
+#[derive(Properties, Clone, PartialEq)]
+pub struct AccordionGroupProps {
+    /// The accordions coordinated by the group.
+    ///
+    /// Accordions rendered below the group join it, except those nested in the panel of another accordion. Defaults to an empty string.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// Whether at most one accordion can be open at a time.
+    ///
+    /// If `true`, opening an accordion closes every other accordion in the group. Defaults to `false`.
+    #[prop_or_default]
+    pub single: bool,
+
+    /// Web storage key under which the group's open accordions are saved and restored.
+    ///
+    /// Accordions are identified by their order in the group. Defaults to `None`.
+    #[prop_or_default]
+    pub persist_key: Option<AttrValue>,
+
+    /// Web storage used with `persist_key`.
+    ///
+    /// Defaults to `PersistStorage::Local`.
+    #[prop_or_default]
+    pub persist_storage: PersistStorage,
+
+    /// Custom inline styles for the group container.
+    ///
+    /// Allows for custom styling of the group container. Defaults to an empty string.
+    #[prop_or_default]
+    pub style: AttrValue,
+
+    /// Custom class for the group container.
+    ///
+    /// Applies a custom CSS class to the group container. Defaults to an empty string.
+    #[prop_or_default]
+    pub class: AttrValue,
+}
+
+/// AccordionGroup Component
+///
+/// A Yew component that owns the expansion state of every `Accordion` rendered inside it,
+/// so the accordions don't need an `expand` handle each. With `single` set, opening one
+/// accordion closes the others.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use accordion_rs::yew::{Accordion, AccordionGroup};
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <AccordionGroup single=true>
+///             <Accordion expanded="First (open)" collapsed="First" />
+///             <Accordion expanded="Second (open)" collapsed="Second" />
+///         </AccordionGroup>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn AccordionGroup(props: &AccordionGroupProps) -> Html {
+    let state = use_reducer(GroupState::default);
+    let members = use_memo((), |_| RefCell::new(GroupMembers::default()));
+    let depth = use_context::<AccordionParent>().map_or(0, |parent| parent.depth + 1);
+
+    {
+        let restored = use_mut_ref(|| false);
+        let dispatcher = state.dispatcher();
+        let persist_key = props.persist_key.clone();
+        let storage = props.persist_storage;
+        let members = members.clone();
+        use_effect_with((*state).clone(), move |state| {
+            let Some(key) = persist_key else {
+                return;
+            };
+            let keys = members.borrow().keys();
+            if !restored.replace(true) {
+                if let Some(saved) = storage.load(&key) {
+                    dispatcher.dispatch(GroupAction::Restore(GroupState::decode(&saved, &keys)));
+                    return;
+                }
+            }
+            storage.save(&key, &state.encode(&keys));
+        });
+    }
+
+    let context = AccordionGroupContext {
+        state,
+        depth,
+        single: props.single,
+        members,
+    };
+
+    html! {
+        <ContextProvider<AccordionGroupContext> {context}>
+            <div class={classes!(GROUP_CLASS, props.class.clone())} style={props.style.clone()}>
+                { props.children.clone() }
+            </div>
+        </ContextProvider<AccordionGroupContext>>
+    }
+}
+
+/// Properties for the AccordionConfigProvider component.
+#[derive(Properties, Clone, PartialEq)]
+pub struct AccordionConfigProviderProps {
+    /// The content whose accordions use the provided defaults.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// Default theme of accordions that don't set one or inherit one from a parent accordion.
+    ///
+    /// Defaults to the surrounding provider's theme, if any.
+    #[prop_or_default]
+    pub theme: Option<Theme>,
+
+    /// Default animation duration in milliseconds.
+    ///
+    /// Defaults to the surrounding provider's duration, or else `600` milliseconds.
+    #[prop_or_default]
+    pub duration: Option<u64>,
+
+    /// Default size of the accordions.
+    ///
+    /// Defaults to the surrounding provider's size, or else `Size::XXLarge`.
+    #[prop_or_default]
+    pub size: Option<Size>,
+
+    /// Whether accordions render ARIA attributes by default.
+    ///
+    /// Defaults to the surrounding provider's value, or else `true`.
+    #[prop_or_default]
+    pub aria_enabled: Option<bool>,
+
+    /// Default heading level of top-level accordions; nested ones step one level below their parent.
+    ///
+    /// Defaults to the surrounding provider's level, or else `3`.
+    #[prop_or_default]
+    pub heading_level: Option<u8>,
+}
+
+/// AccordionConfigProvider Component
+///
+/// A Yew component supplying default `theme`, `duration`, `size`, `aria_enabled` and `heading_level`
+/// values to every `Accordion` rendered inside it. An accordion's own props override the provided
+/// defaults, and nested providers override the fields they set.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use accordion_rs::yew::{Accordion, AccordionConfigProvider};
+/// use accordion_rs::{Size, Theme};
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <AccordionConfigProvider theme={Theme::dark()} duration={300} size={Size::Large} heading_level={2}>
+///             <Accordion expanded="Fast (open)" collapsed="Fast" />
+///             <Accordion expanded="Slow (open)" collapsed="Slow" duration={1000} />
+///         </AccordionConfigProvider>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn AccordionConfigProvider(props: &AccordionConfigProviderProps) -> Html {
+    let outer = use_context::<AccordionConfig>();
+    let context = AccordionConfig {
+        theme: props.theme.clone(),
+        duration: props.duration,
+        size: props.size.clone(),
+        aria_enabled: props.aria_enabled,
+        heading_level: props.heading_level,
+    }
+    .or(outer.as_ref());
+
+    html! {
+        <ContextProvider<AccordionConfig> {context}>
+            { props.children.clone() }
+        </ContextProvider<AccordionConfig>>
+    }
+}
+
 /// Properties for the Item component.
 #[derive(Clone, PartialEq, Properties)]
 pub struct ItemProps {
@@ -332,13 +1632,13 @@ pub struct ItemProps {
     ///
     /// Allows for custom styling of the accordion item. Defaults to an empty string.
     #[prop_or_default]
-    pub style: &'static str,
+    pub style: AttrValue,
 
     /// Additional class for the Item.
     ///
     /// Applies a custom CSS class to the accordion item. Defaults to an empty string.
     #[prop_or_default]
-    pub class: &'static str,
+    pub class: AttrValue,
 
     /// Alignment of the content inside the Item.
     ///
@@ -350,37 +1650,70 @@ pub struct ItemProps {
     ///
     /// Provides the title or heading for the accordion item. Defaults to `None`.
     #[prop_or_default]
-    pub title: &'static str,
+    pub title: AttrValue,
 
     /// The icon for the Item.
     ///
-    /// Specifies an optional icon to be displayed alongside the title. Defaults to `None`.
+    /// Markup displayed alongside the title, such as an emoji, an inline SVG or an icon component.
+    /// Defaults to `None`.
+    #[prop_or_default]
+    pub icon: Option<Html>,
+
+    /// Side of the title on which the icon is displayed.
+    ///
+    /// Defaults to `Placement::Leading`.
+    #[prop_or_default]
+    pub icon_placement: Placement,
+
+    /// Additional class for the `<span>` wrapping the icon.
+    ///
+    /// The span always has the `accordion-item-icon` class, spaced from the title by the default
+    /// stylesheet. Defaults to an empty string.
+    #[prop_or_default]
+    pub icon_class: AttrValue,
+
+    /// Theme supplying the Item's class and style when they are empty.
+    ///
+    /// Defaults to the theme of the surrounding accordion, if any.
     #[prop_or_default]
-    pub icon: &'static str,
+    pub theme: Option<Theme>,
 }
 
 /// Item component.
 #[function_component]
 pub fn Item(props: &ItemProps) -> Html {
+    let theme = use_theme(props.theme.clone());
+    let icon = props.icon.clone().map(|icon| {
+        html! {
+            <span
+                class={classes!(ITEM_ICON_CLASS, props.icon_class.clone())}
+                data-placement={props.icon_placement.as_str()}
+            >
+                { icon }
+            </span>
+        }
+    });
+    let (leading_icon, trailing_icon) = match props.icon_placement {
+        Placement::Leading => (icon, None),
+        Placement::Trailing => (None, icon),
+    };
+
     html! {
         <li
-            class={props.class}
+            class={classes!(ITEM_CLASS, Theme::pick(&props.class, &theme.item_class).to_string())}
             style={format!(
                 "{} {}",
                 props.align.to_style(),
-                props.style
+                Theme::pick(&props.style, &theme.item_style)
             )}
         >
-            { if !props.icon.is_empty() {
-                    html! { <span class="mr-2">{ props.icon }</span> }
-                } else {
-                    html! {}
-                } }
+            { for leading_icon }
             { if !props.title.is_empty() {
-                    html! { <strong>{ props.title }</strong> }
+                    html! { <strong>{ props.title.clone() }</strong> }
                 } else {
                     html! {}
                 } }
+            { for trailing_icon }
             { props.children.clone() }
         </li>
     }
@@ -399,25 +1732,132 @@ pub struct ButtonProps {
     ///
     /// Allows for custom styling of the button. Defaults to an empty string.
     #[prop_or_default]
-    pub style: &'static str,
+    pub style: AttrValue,
 
     /// Additional inline styles for the Button.
     ///
     /// Allows for custom CSS class styling for the button. Defaults to an empty string.
     #[prop_or_default]
-    pub class: &'static str,
+    pub class: AttrValue,
+
+    /// Callback invoked when the Button is clicked.
+    ///
+    /// Defaults to no-op.
+    #[prop_or_default]
+    pub onclick: Callback<MouseEvent>,
 }
 
 /// Button component.
 #[function_component]
 pub fn Button(props: &ButtonProps) -> Html {
     html! {
-        <button class={props.class} style={props.style}>
+        <button class={classes!(BUTTON_CLASS, props.class.clone())} style={props.style.clone()} onclick={props.onclick.clone()}>
             { props.children.clone() }
         </button>
     }
 }
 
+/// Properties for the ExpandAllButton component.
+#[derive(Clone, PartialEq, Properties)]
+pub struct ExpandAllButtonProps {
+    /// The content of the button.
+    ///
+    /// Defaults to `"Expand all"`.
+    #[prop_or_else(|| html! { "Expand all" })]
+    pub children: Html,
+
+    /// Additional inline styles for the button.
+    ///
+    /// Defaults to an empty string.
+    #[prop_or_default]
+    pub style: AttrValue,
+
+    /// Additional CSS class for the button.
+    ///
+    /// Defaults to an empty string.
+    #[prop_or_default]
+    pub class: AttrValue,
+}
+
+/// A `Button` opening every accordion of the surrounding `AccordionGroup`.
+///
+/// Outside of an `AccordionGroup`, the button does nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use accordion_rs::yew::{Accordion, AccordionGroup, CollapseAllButton, ExpandAllButton};
+///
+/// #[function_component(App)]
+/// pub fn app() -> Html {
+///     html! {
+///         <AccordionGroup>
+///             <ExpandAllButton />
+///             <CollapseAllButton>{ "Hide everything" }</CollapseAllButton>
+///             <Accordion expanded="Installation (open)" collapsed="Installation" />
+///             <Accordion expanded="Configuration (open)" collapsed="Configuration" />
+///         </AccordionGroup>
+///     }
+/// }
+/// ```
+#[function_component]
+pub fn ExpandAllButton(props: &ExpandAllButtonProps) -> Html {
+    let group = use_context::<AccordionGroupContext>();
+    let onclick = Callback::from(move |_: MouseEvent| {
+        if let Some(group) = &group {
+            group.expand_all();
+        }
+    });
+
+    html! {
+        <Button class={props.class.clone()} style={props.style.clone()} {onclick}>
+            { props.children.clone() }
+        </Button>
+    }
+}
+
+/// Properties for the CollapseAllButton component.
+#[derive(Clone, PartialEq, Properties)]
+pub struct CollapseAllButtonProps {
+    /// The content of the button.
+    ///
+    /// Defaults to `"Collapse all"`.
+    #[prop_or_else(|| html! { "Collapse all" })]
+    pub children: Html,
+
+    /// Additional inline styles for the button.
+    ///
+    /// Defaults to an empty string.
+    #[prop_or_default]
+    pub style: AttrValue,
+
+    /// Additional CSS class for the button.
+    ///
+    /// Defaults to an empty string.
+    #[prop_or_default]
+    pub class: AttrValue,
+}
+
+/// A `Button` closing every accordion of the surrounding `AccordionGroup`.
+///
+/// Outside of an `AccordionGroup`, the button does nothing.
+#[function_component]
+pub fn CollapseAllButton(props: &CollapseAllButtonProps) -> Html {
+    let group = use_context::<AccordionGroupContext>();
+    let onclick = Callback::from(move |_: MouseEvent| {
+        if let Some(group) = &group {
+            group.collapse_all();
+        }
+    });
+
+    html! {
+        <Button class={props.class.clone()} style={props.style.clone()} {onclick}>
+            { props.children.clone() }
+        </Button>
+    }
+}
+
 /// Properties for the List component.
 #[derive(Clone, PartialEq, Properties)]
 pub struct ListProps {
@@ -431,21 +1871,43 @@ pub struct ListProps {
     ///
     /// Allows for custom styling of the list container. Defaults to an empty string.
     #[prop_or_default]
-    pub style: &'static str,
+    pub style: AttrValue,
 
     /// Additional inline styles for the List.
     ///
     /// Allows for custom CSS class styling for the list container. Defaults to an empty string.
     #[prop_or_default]
-    pub class: &'static str,
+    pub class: AttrValue,
+
+    /// Theme supplying the List's class and style when they are empty.
+    ///
+    /// Defaults to the theme of the surrounding accordion, if any.
+    #[prop_or_default]
+    pub theme: Option<Theme>,
 }
 
 /// List component.
 #[function_component]
 pub fn List(props: &ListProps) -> Html {
+    let theme = use_theme(props.theme.clone());
+
     html! {
-        <ul class={props.class} style={props.style}>
+        <ul
+            class={classes!(LIST_CLASS, Theme::pick(&props.class, &theme.list_class).to_string())}
+            style={Theme::pick(&props.style, &theme.list_style).to_string()}
+        >
             { for props.children.iter() }
         </ul>
     }
 }
+
+/// The given theme, or else the theme of the surrounding accordion or `AccordionConfigProvider`.
+#[hook]
+fn use_theme(theme: Option<Theme>) -> Theme {
+    let parent = use_context::<AccordionParent>();
+    let config = use_context::<AccordionConfig>();
+    theme
+        .or_else(|| parent.and_then(|parent| parent.theme))
+        .or_else(|| config.and_then(|config| config.theme))
+        .unwrap_or_default()
+}